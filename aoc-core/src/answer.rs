@@ -0,0 +1,103 @@
+use serde_json::Value;
+use std::fmt;
+
+// What a part evaluates to, so the runner doesn't need to know each
+// day's return type
+#[derive(Debug, PartialEq, Clone)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    Coord(Vec<i64>),
+    Unsolved,
+}
+
+impl Answer {
+    // Coordinates become arrays and an unsolved part becomes null
+    pub fn to_json(&self) -> Value {
+        match self {
+            Answer::Int(n) => Value::from(*n),
+            Answer::Text(s) => Value::from(s.as_str()),
+            Answer::Coord(c) => Value::from(c.clone()),
+            Answer::Unsolved => Value::Null,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Coord(c) => {
+                let parts: Vec<String> = c.iter().map(|n| n.to_string()).collect();
+                write!(f, "{}", parts.join(","))
+            }
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        Answer::Int(n as i64)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Answer {
+        Answer::Int(n as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.into())
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Answer {
+        Answer::Coord(vec![x as i64, y as i64])
+    }
+}
+
+impl From<(usize, usize, usize)> for Answer {
+    fn from((x, y, z): (usize, usize, usize)) -> Answer {
+        Answer::Coord(vec![x as i64, y as i64, z as i64])
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(x: Option<T>) -> Answer {
+        x.map(Into::into).unwrap_or(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("474", Answer::from(474_usize).to_string());
+        assert_eq!("-3", Answer::from(-3_isize).to_string());
+        assert_eq!("CABDFE", Answer::from("CABDFE").to_string());
+        assert_eq!("69,46", Answer::from((69, 46)).to_string());
+        assert_eq!("229,192,11", Answer::from((229, 192, 11)).to_string());
+        assert_eq!("unsolved", Answer::from(None::<usize>).to_string());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!("474", Answer::from(474_usize).to_json().to_string());
+        assert_eq!("\"CABDFE\"", Answer::from("CABDFE").to_json().to_string());
+        assert_eq!("[69,46]", Answer::from((69, 46)).to_json().to_string());
+        assert_eq!("null", Answer::Unsolved.to_json().to_string());
+    }
+}
@@ -0,0 +1,167 @@
+use nom::types::CompleteStr;
+use nom::{Context, Err, ErrorKind, IResult, InputLength};
+use std::fmt;
+
+// Where the input went wrong. Line and column are both 1-based, column
+// counts characters, and text is the whole offending line.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new<S: Into<String>>(line: usize, column: usize, text: &str, message: S) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Points at byte `offset` within `src`, where `src` starts on line
+    /// `first_line` of the input
+    pub fn locate<S: Into<String>>(
+        src: &str,
+        first_line: usize,
+        offset: usize,
+        message: S,
+    ) -> ParseError {
+        let offset = floor_char_boundary(src, offset);
+        let line_start = src[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = src[offset..]
+            .find('\n')
+            .map(|i| offset + i)
+            .unwrap_or_else(|| src.len());
+        let line = first_line + src[..line_start].matches('\n').count();
+        let column = src[line_start..offset].chars().count() + 1;
+        let text = src[line_start..line_end].trim_end_matches('\r');
+        ParseError::new(line, column, text, message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}: {:?}",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn floor_char_boundary(src: &str, offset: usize) -> usize {
+    let mut offset = offset.min(src.len());
+    while !src.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+fn describe(kind: &ErrorKind) -> String {
+    match kind {
+        ErrorKind::Tag => "unexpected text".into(),
+        ErrorKind::Digit => "expected a number".into(),
+        ErrorKind::MapRes => "invalid value".into(),
+        ErrorKind::Alt => "unrecognized entry".into(),
+        ErrorKind::Many1 => "expected at least one entry".into(),
+        ErrorKind::Count => "wrong number of entries".into(),
+        ErrorKind::Eof => "expected end of line".into(),
+        ErrorKind::OneOf => "unexpected character".into(),
+        other => format!("failed to match {}", other.description().to_lowercase()),
+    }
+}
+
+/// Turns the result of a nom parser run over `src` into a `ParseError`
+/// pointing at wherever the parser stopped, including when it left
+/// unparsed input behind
+pub fn finish<I, T>(src: &str, first_line: usize, result: IResult<I, T>) -> Result<T, ParseError>
+where
+    I: InputLength,
+{
+    let consumed = |rest: &I| src.len().saturating_sub(rest.input_len());
+    match result {
+        Ok((ref rest, _)) if rest.input_len() > 0 => Err(ParseError::locate(
+            src,
+            first_line,
+            consumed(rest),
+            "unexpected trailing input",
+        )),
+        Ok((_, value)) => Ok(value),
+        Err(Err::Incomplete(_)) => Err(ParseError::locate(
+            src,
+            first_line,
+            src.len(),
+            "unexpected end of input",
+        )),
+        Err(Err::Error(Context::Code(rest, kind)))
+        | Err(Err::Failure(Context::Code(rest, kind))) => Err(ParseError::locate(
+            src,
+            first_line,
+            consumed(&rest),
+            describe(&kind),
+        )),
+    }
+}
+
+// nom's `CompleteStr` doesn't implement `FromStr` itself
+pub fn parse_str<T: std::str::FromStr>(s: CompleteStr) -> Result<T, T::Err> {
+    s.0.parse()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    named!(pair<CompleteStr, (usize, usize)>,
+           do_parse!(
+               a: map_res!(nom::digit, parse_str) >>
+                   tag!(",") >>
+                   b: map_res!(nom::digit, parse_str) >>
+                   ((a, b))));
+
+    #[test]
+    fn test_locate() {
+        let src = "abc\ndef\nghi";
+        assert_eq!(
+            ParseError::new(1, 1, "abc", "x"),
+            ParseError::locate(src, 1, 0, "x")
+        );
+        assert_eq!(
+            ParseError::new(2, 3, "def", "x"),
+            ParseError::locate(src, 1, 6, "x")
+        );
+        assert_eq!(
+            ParseError::new(12, 4, "ghi", "x"),
+            ParseError::locate(src, 10, 11, "x")
+        );
+    }
+
+    #[test]
+    fn test_finish() {
+        let line = "12,x";
+        assert_eq!(
+            Err(ParseError::new(5, 4, line, "expected a number")),
+            finish(line, 5, pair(CompleteStr(line)))
+        );
+
+        let line = "12,34 extra";
+        assert_eq!(
+            Err(ParseError::new(1, 6, line, "unexpected trailing input")),
+            finish(line, 1, pair(CompleteStr(line)))
+        );
+
+        let line = "12,99999999999999999999999";
+        assert_eq!(
+            Err(ParseError::new(1, 4, line, "invalid value")),
+            finish(line, 1, pair(CompleteStr(line)))
+        );
+
+        assert_eq!(Ok((12, 34)), finish("12,34", 1, pair(CompleteStr("12,34"))));
+    }
+}
@@ -0,0 +1,188 @@
+// Shared pieces that let the runner (and each day's binary) treat every
+// day the same way
+
+#[cfg_attr(test, macro_use)]
+extern crate nom;
+
+mod answer;
+mod error;
+
+pub use crate::answer::Answer;
+pub use crate::error::{finish, parse_str, ParseError};
+
+use serde_json::json;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, prelude::*};
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+// A part's answer along with how long the part took, not counting parsing
+#[derive(Debug, PartialEq, Clone)]
+pub struct Solved {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl Solved {
+    /// One line object with the day, part, typed answer and elapsed time
+    pub fn to_json(&self) -> String {
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "answer": self.answer.to_json(),
+            "elapsed_ns": self.elapsed.as_nanos() as u64,
+        })
+        .to_string()
+    }
+
+    pub fn print(&self, json: bool) {
+        if json {
+            println!("{}", self.to_json());
+        } else {
+            println!("{}", self);
+        }
+    }
+}
+
+impl fmt::Display for Solved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day{}.{} {}", self.day, self.part, self.answer)
+    }
+}
+
+/// Parses once and runs each of the requested parts (1 and/or 2)
+pub fn solve<S: Solution>(buf: &str, parts: &[u8]) -> Result<Vec<Solved>, ParseError> {
+    let input = S::parse(buf)?;
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            };
+            Solved {
+                day: S::DAY,
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+/// Body of every day's `main`, prints both parts for the input named by the
+/// first argument (`-` for stdin, `input-dayN` if there isn't one), as JSON
+/// with `--json`, exiting nonzero if the input can't be read or parsed
+pub fn run<S: Solution>() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let json = args.iter().any(|a| a == "--json");
+    args.retain(|a| a != "--json");
+    let path = match args.as_slice() {
+        [] => format!("input-day{}", S::DAY),
+        [path] => path.clone(),
+        _ => {
+            eprintln!("usage: day{} [--json] [<input path> | -]", S::DAY);
+            process::exit(2);
+        }
+    };
+    if let Err(e) = run_with::<S>(&path, json) {
+        eprintln!("error: {}: {}", path, e);
+        process::exit(1);
+    }
+}
+
+fn run_with<S: Solution>(path: &str, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let buf = read_input(path)?;
+    for solved in solve::<S>(&buf, &[1, 2])? {
+        solved.print(json);
+    }
+
+    Ok(())
+}
+
+/// Reads the whole of the file at `path`, or stdin if `path` is `-`
+pub fn read_input<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let mut buf = String::new();
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        io::stdin().read_to_string(&mut buf)?;
+    } else {
+        File::open(path)?.read_to_string(&mut buf)?;
+    }
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        type Input = Vec<usize>;
+
+        fn parse(buf: &str) -> Result<Vec<usize>, ParseError> {
+            buf.split(',')
+                .map(|n| {
+                    n.parse()
+                        .map_err(|_| ParseError::new(1, 1, n, "not a number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Vec<usize>) -> Answer {
+            input.iter().sum::<usize>().into()
+        }
+
+        fn part2(input: &Vec<usize>) -> Answer {
+            input.iter().max().cloned().into()
+        }
+    }
+
+    fn answers(buf: &str, parts: &[u8]) -> Result<Vec<(u8, Answer)>, ParseError> {
+        solve::<Sum>(buf, parts).map(|v| v.into_iter().map(|s| (s.part, s.answer)).collect())
+    }
+
+    #[test]
+    fn test_to_json() {
+        let solved = Solved {
+            day: 13,
+            part: 1,
+            answer: Answer::from((69, 46)),
+            elapsed: Duration::from_micros(1500),
+        };
+        assert_eq!(
+            r#"{"answer":[69,46],"day":13,"elapsed_ns":1500000,"part":1}"#,
+            solved.to_json()
+        );
+        assert_eq!("day13.1 69,46", solved.to_string());
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            Ok(vec![(1, Answer::Int(6)), (2, Answer::Int(3))]),
+            answers("1,2,3", &[1, 2])
+        );
+        assert_eq!(Ok(vec![(2, Answer::Int(3))]), answers("1,2,3", &[2]));
+        assert_eq!(
+            Err(ParseError::new(1, 1, "x", "not a number")),
+            answers("1,x", &[1])
+        );
+    }
+}
@@ -0,0 +1,66 @@
+// Benchmarks parse, part1 and part2 of every day against the checked-in
+// inputs
+//
+//   cargo bench -p aoc [-- <filter>]
+//
+// e.g. `-- day9/` for a single day or `-- part2` for every part2. Days
+// that take seconds per run (day11's part2 especially) only take the
+// minimum number of samples, so a full run is still slow.
+
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, Criterion};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    bench::<S>(c, 100);
+}
+
+fn bench_slow_day<S: Solution>(c: &mut Criterion) {
+    bench::<S>(c, 10);
+}
+
+fn bench<S: Solution>(c: &mut Criterion, sample_size: usize) {
+    let path = format!("input-day{}", S::DAY);
+    let buf = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    let input = S::parse(&buf).unwrap_or_else(|e| panic!("{}: {}", path, e));
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.sample_size(sample_size);
+    if sample_size < 100 {
+        group.measurement_time(Duration::from_secs(30));
+    }
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&buf))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_day::<day1::Day1>,
+    bench_day::<day2::Day2>,
+    bench_day::<day3::Day3>,
+    bench_day::<day4::Day4>,
+    bench_day::<day5::Day5>,
+    bench_slow_day::<day6::Day6>,
+    bench_day::<day7::Day7>,
+    bench_day::<day8::Day8>,
+    bench_slow_day::<day9::Day9>,
+    bench_slow_day::<day10::Day10>,
+    bench_slow_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+    bench_day::<day13::Day13>,
+    bench_slow_day::<day14::Day14>,
+    bench_day::<day15::Day15>,
+    bench_day::<day16::Day16>,
+);
+
+// The inputs live in the workspace root, so run from there rather than from
+// this crate
+fn main() {
+    std::env::set_current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    benches();
+    Criterion::default().configure_from_args().final_summary();
+}
@@ -0,0 +1,126 @@
+// Known-good answers, read from a TOML file keyed by day and part
+//
+//   [day1]
+//   part1 = 474
+//   part2 = 137041
+//
+//   [day13]
+//   part1 = [69, 46]
+//
+// Integers, strings and arrays of integers (coordinates) become the
+// matching `Answer` variant.
+
+use aoc_core::Answer;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use toml::Value;
+
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u8, u8), Answer>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let buf = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Answers::parse(&buf).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(buf: &str) -> Result<Answers, String> {
+        let table = match buf.parse::<Value>().map_err(|e| e.to_string())? {
+            Value::Table(table) => table,
+            _ => unreachable!(),
+        };
+
+        let mut answers = HashMap::new();
+        for (day_key, parts) in table {
+            let day = number_suffix(&day_key, "day")?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("{} should be a table of parts", day_key))?;
+            for (part_key, value) in parts {
+                let part = number_suffix(part_key, "part")?;
+                if part != 1 && part != 2 {
+                    return Err(format!("{}.{}: part must be 1 or 2", day_key, part_key));
+                }
+                let answer = to_answer(value).ok_or_else(|| {
+                    format!("{}.{}: unsupported answer {}", day_key, part_key, value)
+                })?;
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+}
+
+fn number_suffix(key: &str, prefix: &str) -> Result<u8, String> {
+    key.strip_prefix(prefix)
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| format!("expected {}N, found {}", prefix, key))
+}
+
+fn to_answer(value: &Value) -> Option<Answer> {
+    match value {
+        Value::Integer(n) => Some(Answer::Int(*n)),
+        Value::String(s) => Some(Answer::Text(s.clone())),
+        Value::Array(values) => values
+            .iter()
+            .map(Value::as_integer)
+            .collect::<Option<Vec<i64>>>()
+            .map(Answer::Coord),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            r#"
+[day2]
+part1 = 4693
+part2 = "pebjqsalrdnckzfihvtxysomg"
+
+[day13]
+part1 = [69, 46]
+"#,
+        )
+        .unwrap();
+        assert_eq!(Some(&Answer::Int(4693)), answers.get(2, 1));
+        assert_eq!(
+            Some(&Answer::Text("pebjqsalrdnckzfihvtxysomg".into())),
+            answers.get(2, 2)
+        );
+        assert_eq!(Some(&Answer::Coord(vec![69, 46])), answers.get(13, 1));
+        assert_eq!(None, answers.get(13, 2));
+    }
+
+    #[test]
+    fn test_parse_real() {
+        let answers = Answers::parse(include_str!("../../answers.toml")).unwrap();
+        assert_eq!(30, answers.0.len());
+        assert_eq!(Some(&Answer::Coord(vec![229, 192, 11])), answers.get(11, 2));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            "expected dayN, found day_one",
+            Answers::parse("[day_one]\npart1 = 1").unwrap_err()
+        );
+        assert_eq!(
+            "day1.part3: part must be 1 or 2",
+            Answers::parse("[day1]\npart3 = 1").unwrap_err()
+        );
+        assert_eq!(
+            "day1.part1: unsupported answer 1.5",
+            Answers::parse("[day1]\npart1 = 1.5").unwrap_err()
+        );
+    }
+}
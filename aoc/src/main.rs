@@ -0,0 +1,262 @@
+// Runs any of the days from a single binary
+//
+//   aoc run <day> [--part <1|2>] [--input <path> | -] [--json]
+//   aoc all [--input-dir <dir>] [--json]
+//   aoc verify [--input-dir <dir>] [--answers <path>] [--day <day>]
+//
+// Input defaults to `input-dayN` in the current directory, same as the
+// individual day binaries, and `--input -` reads it from stdin. `--json`
+// prints one JSON object per part instead of `dayN.P answer` lines.
+// `verify` checks each part against the recorded answers (`answers.toml`
+// next to the inputs by default) and exits nonzero if any of them fail.
+
+mod answers;
+
+use crate::answers::Answers;
+use aoc_core::{read_input, solve, ParseError, Solved};
+use std::path::{Path, PathBuf};
+use std::process;
+
+const DAYS: std::ops::RangeInclusive<u8> = 1..=16;
+
+type Solver = fn(&str, &[u8]) -> Result<Vec<Solved>, ParseError>;
+
+const SOLUTIONS: [Solver; 16] = [
+    solve::<day1::Day1>,
+    solve::<day2::Day2>,
+    solve::<day3::Day3>,
+    solve::<day4::Day4>,
+    solve::<day5::Day5>,
+    solve::<day6::Day6>,
+    solve::<day7::Day7>,
+    solve::<day8::Day8>,
+    solve::<day9::Day9>,
+    solve::<day10::Day10>,
+    solve::<day11::Day11>,
+    solve::<day12::Day12>,
+    solve::<day13::Day13>,
+    solve::<day14::Day14>,
+    solve::<day15::Day15>,
+    solve::<day16::Day16>,
+];
+
+const USAGE: &str = "usage:
+    aoc run <day> [--part <1|2>] [--input <path> | -] [--json]
+    aoc all [--input-dir <dir>] [--json]
+    aoc verify [--input-dir <dir>] [--answers <path>] [--day <day>]";
+
+enum Command {
+    Run {
+        day: u8,
+        part: Option<u8>,
+        input: Option<PathBuf>,
+        json: bool,
+    },
+    All {
+        input_dir: PathBuf,
+        json: bool,
+    },
+    Verify {
+        input_dir: PathBuf,
+        answers: Option<PathBuf>,
+        day: Option<u8>,
+    },
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cmd = match parse_args(&args) {
+        Ok(cmd) => cmd,
+        Err(msg) => {
+            eprintln!("error: {}\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match cmd {
+        Command::Run {
+            day,
+            part,
+            input,
+            json,
+        } => {
+            let path = input.unwrap_or_else(|| default_input(Path::new("."), day));
+            run_day(day, part, &path, json)
+        }
+        Command::All { input_dir, json } => run_all(&input_dir, json),
+        Command::Verify {
+            input_dir,
+            answers,
+            day,
+        } => {
+            let answers = answers.unwrap_or_else(|| input_dir.join("answers.toml"));
+            verify(&input_dir, &answers, day)
+        }
+    };
+
+    if let Err(msg) = result {
+        eprintln!("error: {}", msg);
+        process::exit(1);
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    match args.next().map(String::as_str) {
+        Some("run") => {
+            let day = parse_day(args.next().ok_or("missing day")?)?;
+
+            let mut part = None;
+            let mut input = None;
+            let mut json = false;
+            while let Some(flag) = args.next() {
+                if flag == "--json" {
+                    json = true;
+                    continue;
+                }
+                let value = args.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--part" => match value.as_str() {
+                        "1" => part = Some(1),
+                        "2" => part = Some(2),
+                        _ => return Err("part must be 1 or 2".into()),
+                    },
+                    "--input" => input = Some(PathBuf::from(value)),
+                    _ => return Err(format!("unknown option {}", flag)),
+                }
+            }
+            Ok(Command::Run {
+                day,
+                part,
+                input,
+                json,
+            })
+        }
+        Some("all") => {
+            let mut input_dir = PathBuf::from(".");
+            let mut json = false;
+            while let Some(flag) = args.next() {
+                if flag == "--json" {
+                    json = true;
+                    continue;
+                }
+                let value = args.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--input-dir" => input_dir = PathBuf::from(value),
+                    _ => return Err(format!("unknown option {}", flag)),
+                }
+            }
+            Ok(Command::All { input_dir, json })
+        }
+        Some("verify") => {
+            let mut input_dir = PathBuf::from(".");
+            let mut answers = None;
+            let mut day = None;
+            while let Some(flag) = args.next() {
+                let value = args.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--input-dir" => input_dir = PathBuf::from(value),
+                    "--answers" => answers = Some(PathBuf::from(value)),
+                    "--day" => day = Some(parse_day(value)?),
+                    _ => return Err(format!("unknown option {}", flag)),
+                }
+            }
+            Ok(Command::Verify {
+                input_dir,
+                answers,
+                day,
+            })
+        }
+        Some(other) => Err(format!("unknown command {}", other)),
+        None => Err("missing command".into()),
+    }
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    let day: u8 = arg.parse().map_err(|_| "day must be a number")?;
+    if !DAYS.contains(&day) {
+        return Err(format!("no solution for day {}", day));
+    }
+    Ok(day)
+}
+
+fn default_input(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("input-day{}", day))
+}
+
+fn run_all(input_dir: &Path, json: bool) -> Result<(), String> {
+    for day in DAYS {
+        run_day(day, None, &default_input(input_dir, day), json)?;
+    }
+    Ok(())
+}
+
+fn run_day(day: u8, part: Option<u8>, path: &Path, json: bool) -> Result<(), String> {
+    let parts: &[u8] = match part {
+        Some(1) => &[1],
+        Some(2) => &[2],
+        _ => &[1, 2],
+    };
+    for solved in solve_day(day, parts, path)? {
+        solved.print(json);
+    }
+    Ok(())
+}
+
+fn solve_day(day: u8, parts: &[u8], path: &Path) -> Result<Vec<Solved>, String> {
+    let buf = read_input(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let solve = SOLUTIONS[day as usize - 1];
+    solve(&buf, parts).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn verify(input_dir: &Path, answers: &Path, day: Option<u8>) -> Result<(), String> {
+    let answers = Answers::load(answers)?;
+    let days = match day {
+        Some(day) => day..=day,
+        None => DAYS,
+    };
+
+    let (mut passed, mut failed, mut missing, mut errors) = (0, 0, 0, 0);
+    for day in days {
+        let solved = match solve_day(day, &[1, 2], &default_input(input_dir, day)) {
+            Ok(solved) => solved,
+            Err(e) => {
+                println!("day{}   error    {}", day, e);
+                errors += 1;
+                continue;
+            }
+        };
+        for Solved {
+            part,
+            answer,
+            elapsed,
+            ..
+        } in solved
+        {
+            let status = match answers.get(day, part) {
+                None => {
+                    missing += 1;
+                    format!("missing  {}", answer)
+                }
+                Some(expected) if *expected == answer => {
+                    passed += 1;
+                    format!("pass     {}", answer)
+                }
+                Some(expected) => {
+                    failed += 1;
+                    format!("FAIL     {} (expected {})", answer, expected)
+                }
+            };
+            println!("day{}.{} {} [{:.2?}]", day, part, status, elapsed);
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} missing, {} errors",
+        passed, failed, missing, errors
+    );
+    if failed + errors > 0 {
+        return Err("verification failed".into());
+    }
+    Ok(())
+}
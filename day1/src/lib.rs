@@ -0,0 +1,359 @@
+#![allow(clippy::assign_op_pattern, clippy::unnecessary_fold)]
+
+#[macro_use]
+extern crate nom;
+
+use aoc_core::{finish, parse_str, Answer, ParseError, Solution};
+use nom::types::CompleteStr;
+use nom::{digit, multispace};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead};
+use std::iter::Enumerate;
+use std::vec;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<isize>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        freq(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        first_repeat_residues(input).into()
+    }
+}
+
+/// Changes are signed integers (the sign is optional), separated by
+/// newlines and/or commas. Blank lines are skipped.
+pub fn parse_input(buf: &str) -> Result<Vec<isize>, ParseError> {
+    let mut mods = Vec::new();
+    for (i, l) in buf.split('\n').enumerate() {
+        mods.extend(parse_line(i + 1, l)?);
+    }
+    Ok(mods)
+}
+
+fn parse_line(line: usize, l: &str) -> Result<Vec<isize>, ParseError> {
+    if l.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    finish(l, line, changes(CompleteStr(l)))
+}
+
+named!(change<CompleteStr, isize>,
+       do_parse!(
+           opt!(multispace) >>
+               sign: opt!(one_of!("+-")) >>
+               n: map_res!(digit, parse_str::<isize>) >>
+               opt!(multispace) >>
+               (if sign == Some('-') { -n } else { n })));
+
+named!(changes<CompleteStr, Vec<isize>>,
+       separated_nonempty_list!(tag!(","), change));
+
+/// Reads changes a line at a time from `reader`, yielding the running
+/// frequency after each one. Malformed lines come back as `InvalidData`.
+pub struct Calibrator<R> {
+    lines: Enumerate<io::Lines<R>>,
+    pending: vec::IntoIter<isize>,
+    frequency: isize,
+}
+
+impl<R: BufRead> Calibrator<R> {
+    pub fn new(reader: R) -> Calibrator<R> {
+        Calibrator {
+            lines: reader.lines().enumerate(),
+            pending: Vec::new().into_iter(),
+            frequency: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Calibrator<R> {
+    type Item = io::Result<isize>;
+
+    fn next(&mut self) -> Option<io::Result<isize>> {
+        loop {
+            if let Some(change) = self.pending.next() {
+                self.frequency += change;
+                return Some(Ok(self.frequency));
+            }
+
+            let (i, line) = self.lines.next()?;
+            let changes = line.and_then(|l| {
+                parse_line(i + 1, &l).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            });
+            match changes {
+                Ok(changes) => self.pending = changes.into_iter(),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+pub fn freq(mods: &[isize]) -> isize {
+    mods.iter().fold(0isize, |acc, x| acc + x)
+}
+
+pub fn twice(mods: &[isize]) -> isize {
+    let mut uniqs: HashMap<isize, usize> = HashMap::new();
+    let mut start = 0isize;
+    uniqs.insert(start, 1);
+    loop {
+        let freq = mods.iter().scan(start, |acc, x| {
+            *acc += x;
+            Some(*acc)
+        });
+        start = mods.iter().fold(start, |acc, x| acc + x);
+
+        for x in freq {
+            let ent = uniqs.entry(x).and_modify(|e| *e = *e + 1).or_insert(1);
+            if *ent == 2 {
+                return x;
+            }
+        }
+    }
+}
+
+/// Like `twice`, but returns None for inputs that never repeat a frequency
+/// rather than looping forever
+pub fn first_repeat(mods: &[isize]) -> Option<isize> {
+    let mut seen: HashSet<isize> = HashSet::new();
+    seen.insert(0);
+    let mut current = 0;
+    for m in mods {
+        current += m;
+        if !seen.insert(current) {
+            return Some(current);
+        }
+    }
+
+    // Every later cycle is the first one shifted by the drift, so a
+    // frequency can only come around again if two of the first cycle's
+    // starting points are congruent modulo the drift
+    let drift = freq(mods);
+    if drift == 0 {
+        return None;
+    }
+    let mut residues = HashSet::new();
+    let starts = mods.iter().scan(0, |acc, m| {
+        let start = *acc;
+        *acc += m;
+        Some(start)
+    });
+    if starts
+        .map(|f| f.rem_euclid(drift.abs()))
+        .all(|r| residues.insert(r))
+    {
+        return None;
+    }
+
+    Some(twice(mods))
+}
+
+/// Same answer as `first_repeat` without simulating the cycles. Frequency
+/// `k * drift + start` comes up at step `k * len + i` for each of the first
+/// cycle's starting points, so a repeat is some start climbing to the next
+/// start above it in the same residue class modulo the drift, and the first
+/// repeat is whichever of those climbs finishes soonest.
+pub fn first_repeat_residues(mods: &[isize]) -> Option<isize> {
+    let mut seen: HashSet<isize> = HashSet::new();
+    seen.insert(0);
+    let mut current = 0;
+    for m in mods {
+        current += m;
+        if !seen.insert(current) {
+            return Some(current);
+        }
+    }
+
+    let drift = freq(mods);
+    if drift == 0 {
+        return None;
+    }
+    // Flip a negative drift so every class climbs upwards
+    let sign = drift.signum();
+    let mut starts: Vec<(isize, isize, usize)> = mods
+        .iter()
+        .scan(0, |acc, m| {
+            let start = *acc;
+            *acc += m;
+            Some(start)
+        })
+        .enumerate()
+        .map(|(i, start)| {
+            let start = start * sign;
+            (start.rem_euclid(drift.abs()), start, i)
+        })
+        .collect();
+    starts.sort_unstable();
+
+    starts
+        .windows(2)
+        .filter(|w| w[0].0 == w[1].0)
+        .map(|w| {
+            let (_, from, i) = w[0];
+            let (_, to, _) = w[1];
+            let cycles = ((to - from) / drift.abs()) as usize;
+            (cycles * mods.len() + i, to * sign)
+        })
+        .min()
+        .map(|(_, freq)| freq)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_twice() {
+        let input = vec![1, -1];
+        assert_eq!(twice(&input), 0);
+        let input = vec![3, 3, 4, -2, -4];
+        assert_eq!(twice(&input), 10);
+        let input = vec![-6, 3, 8, 5, -6];
+        assert_eq!(twice(&input), 5);
+        let input = vec![7, 7, -2, -7, -4];
+        assert_eq!(twice(&input), 14);
+    }
+
+    #[test]
+    fn test_first_repeat() {
+        assert_eq!(None, first_repeat(&[1]));
+        assert_eq!(None, first_repeat(&[]));
+        assert_eq!(None, first_repeat(&[3, 1]));
+        assert_eq!(Some(0), first_repeat(&[1, -1]));
+        assert_eq!(Some(10), first_repeat(&[3, 3, 4, -2, -4]));
+        assert_eq!(Some(5), first_repeat(&[-6, 3, 8, 5, -6]));
+        assert_eq!(Some(14), first_repeat(&[7, 7, -2, -7, -4]));
+    }
+
+    #[test]
+    fn test_first_repeat_residues() {
+        let examples: &[&[isize]] = &[
+            &[1, -1],
+            &[3, 3, 4, -2, -4],
+            &[-6, 3, 8, 5, -6],
+            &[7, 7, -2, -7, -4],
+            &[-3, -3, -4, 2, 4],
+            &[7, -3, -1],
+        ];
+        for mods in examples {
+            assert_eq!(Some(twice(mods)), first_repeat_residues(mods));
+        }
+        assert_eq!(None, first_repeat_residues(&[1]));
+        assert_eq!(None, first_repeat_residues(&[]));
+        assert_eq!(None, first_repeat_residues(&[3, 1]));
+    }
+
+    #[test]
+    fn test_first_repeat_residues_random() {
+        // small LCG so the inputs are the same every run
+        let mut seed = 0x2545_f491_u64;
+        let mut next = |n: u64| {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            (seed >> 33) % n
+        };
+        for _ in 0..500 {
+            let len = next(12) as usize + 1;
+            let mods: Vec<isize> = (0..len).map(|_| next(21) as isize - 10).collect();
+            assert_eq!(
+                first_repeat(&mods),
+                first_repeat_residues(&mods),
+                "{:?}",
+                mods
+            );
+        }
+    }
+
+    #[test]
+    fn test_calibrator() {
+        let input = io::Cursor::new("+3\n+3\n\n+4, -2\r\n-4\n");
+        let freqs: Vec<isize> = Calibrator::new(input).map(Result::unwrap).collect();
+        assert_eq!(vec![3, 6, 10, 8, 4], freqs);
+
+        let input = io::Cursor::new("+3\n+x\n+4\n");
+        let mut calibrator = Calibrator::new(input);
+        assert_eq!(3, calibrator.next().unwrap().unwrap());
+        let err = calibrator.next().unwrap().unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!(
+            r#"line 2, column 2: expected a number: "+x""#,
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_calibrator_real() {
+        let buf = include_str!("../../input-day1");
+        let last = Calibrator::new(buf.as_bytes()).last().unwrap().unwrap();
+        assert_eq!(474, last);
+    }
+
+    #[test]
+    fn test_day1() {
+        let buf = include_str!("../../input-day1");
+        let mods = parse_input(buf).unwrap();
+        assert_eq!(freq(&mods), 474);
+    }
+
+    #[test]
+    fn test_day2() {
+        let buf = include_str!("../../input-day1");
+        let mods = parse_input(buf).unwrap();
+        assert_eq!(twice(&mods), 137041);
+        assert_eq!(first_repeat(&mods), Some(137041));
+        assert_eq!(first_repeat_residues(&mods), Some(137041));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(Ok(vec![1, -2, 3, 1]), parse_input("+1\n-2\n+3\n+1\n"));
+        assert_eq!(
+            Ok(vec![1, -2, 3, 1]),
+            parse_input("+1\r\n-2\r\n\r\n3\r\n+1")
+        );
+        assert_eq!(Ok(vec![1, -2, 3, 1]), parse_input("+1, -2, +3, +1"));
+        assert_eq!(
+            Ok(vec![1, -2, 3, 1]),
+            parse_input("  +1 ,-2\n\t3,  1  \n\n")
+        );
+        assert_eq!(Ok(vec![]), parse_input(""));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(ParseError::new(2, 2, "+x", "expected a number")),
+            parse_input("+1\n+x\n-2")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 1, "x5", "expected a number")),
+            parse_input("x5")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 3, "+1,,+2", "unexpected trailing input")),
+            parse_input("+1,,+2")
+        );
+        assert_eq!(
+            Err(ParseError::new(3, 5, "+12 3", "unexpected trailing input")),
+            parse_input("+1\n\n+12 3")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                2,
+                "-99999999999999999999",
+                "invalid value"
+            )),
+            parse_input("-99999999999999999999")
+        );
+    }
+}
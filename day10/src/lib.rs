@@ -0,0 +1,207 @@
+#![allow(clippy::useless_conversion)]
+
+#[macro_use]
+extern crate nom;
+
+use aoc_core::{finish, Answer, ParseError, Solution};
+use image::{GrayImage, ImageBuffer, Luma};
+use itertools::Itertools;
+use nom::types::CompleteStr;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Point>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub fn parse_input(buf: &str) -> Result<Vec<Point>, ParseError> {
+    buf.lines()
+        .enumerate()
+        .map(|(i, ln)| finish(ln, i + 1, parse_point(CompleteStr(ln))))
+        .collect()
+}
+
+fn find_min_ts(points: &[Point], t0: usize, t1: usize) -> usize {
+    (t0..=t1)
+        .into_iter()
+        .map(|t| {
+            let pos: Vec<Position> = points.iter().map(|p| p.at_time(t)).collect();
+            let (_, _, width, height) = canvas(&pos);
+            (t, width + height)
+        })
+        .min_by(|(_, x), (_, y)| x.cmp(y))
+        .unwrap()
+        .0
+}
+
+/// The message as text, `#` for each point
+pub fn part1(points: &[Point]) -> String {
+    let (_, img) = render_message(points);
+    message_text(&img)
+}
+
+/// The second the message appears and the points at that second drawn one
+/// pixel each
+pub fn render_message(points: &[Point]) -> (usize, GrayImage) {
+    let t = find_min_ts(points, 10000, 50000);
+
+    let current_pos: Vec<Position> = points.iter().map(|p| p.at_time(t)).collect();
+
+    let (offset_x, offset_y, width, height) = canvas(&current_pos);
+
+    let mut img = ImageBuffer::new(width as u32 + 1, height as u32 + 1);
+
+    let px = Luma([255u8]);
+    for Position(x, y) in current_pos.iter() {
+        img.put_pixel((x + offset_x) as u32, (y + offset_y) as u32, px);
+    }
+
+    (t, img)
+}
+
+// One line per row, leaving out the empty rows above and below the message
+fn message_text(img: &GrayImage) -> String {
+    let lit = |x, y| img.get_pixel(x, y).data[0] > 0;
+    let rows: Vec<String> = (0..img.height())
+        .map(|y| {
+            (0..img.width())
+                .map(|x| if lit(x, y) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    let first = rows.iter().position(|r| r.contains('#')).unwrap_or(0);
+    let last = rows
+        .iter()
+        .rposition(|r| r.contains('#'))
+        .map_or(0, |l| l + 1);
+    rows[first..last].join("\n")
+}
+
+/// The number of seconds until the message appears
+pub fn part2(points: &[Point]) -> usize {
+    find_min_ts(points, 10000, 50000)
+}
+
+// offset_x, offset_y
+fn canvas(positions: &[Position]) -> (isize, isize, usize, usize) {
+    use itertools::MinMaxResult;
+    let (x_offset, width) = match positions.iter().map(|p| p.0).minmax() {
+        MinMaxResult::NoElements => unreachable!(),
+        MinMaxResult::OneElement(x) => {
+            if x < 0 {
+                (x.abs(), 0)
+            } else {
+                (0 - x, 0)
+            }
+        }
+        MinMaxResult::MinMax(x, y) => {
+            let off = if x < 0 { x.abs() } else { 0 - x };
+            let width = y + off;
+            (off, width)
+        }
+    };
+    let (y_offset, height) = match positions.iter().map(|p| p.0).minmax() {
+        MinMaxResult::NoElements => unreachable!(),
+        MinMaxResult::OneElement(x) => {
+            if x < 0 {
+                (x.abs(), 0)
+            } else {
+                (0 - x, 0)
+            }
+        }
+        MinMaxResult::MinMax(x, y) => {
+            let off = if x < 0 { x.abs() } else { 0 - x };
+            let height = y + off;
+            (off, height)
+        }
+    };
+    (x_offset, y_offset, width as usize, height as usize)
+}
+
+#[derive(Debug, Clone)]
+pub struct Point {
+    position: Position,
+    velocity: Velocity,
+}
+
+impl Point {
+    fn at_time(&self, t: usize) -> Position {
+        let mod_x = self.velocity.0 * t as isize;
+        let mod_y = self.velocity.1 * t as isize;
+        Position(self.position.0 + mod_x, self.position.1 + mod_y)
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position(isize, isize);
+#[derive(Debug, Clone, PartialEq)]
+pub struct Velocity(isize, isize);
+
+// fields are fixed width, padded with spaces
+fn padded_number(s: CompleteStr) -> Result<isize, std::num::ParseIntError> {
+    s.trim().parse()
+}
+
+named!(parse_point<CompleteStr, Point>,
+       do_parse!(
+           tag!("position=<") >>
+               pos_x: map_res!(take!(6), padded_number) >>
+               tag!(", ") >>
+               pos_y: map_res!(take!(6), padded_number) >>
+               tag!("> velocity=<") >>
+               vel_x: map_res!(take!(2), padded_number) >>
+               tag!(", ") >>
+               vel_y: map_res!(take!(2), padded_number) >>
+               tag!(">") >>
+               (Point{
+                   position: Position(pos_x, pos_y),
+                   velocity: Velocity(vel_x, vel_y),
+               })));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let buf = include_str!("../../input-day10");
+        let points = parse_input(buf).unwrap();
+        assert_eq!(Position(-30509, 41062), points[0].position);
+        assert_eq!(Velocity(3, -4), points[0].velocity);
+
+        let buf = "position=<-30509,  41062> velocity=< 3, -x>";
+        assert_eq!(
+            Err(ParseError::new(1, 41, buf, "invalid value")),
+            parse_input(buf).map(|_| ())
+        );
+    }
+    #[test]
+    fn test_render_message() {
+        let points = parse_input(include_str!("../../input-day10")).unwrap();
+        let (t, img) = render_message(&points);
+        assert_eq!(10227, t);
+        assert_eq!((62, 62), img.dimensions());
+    }
+
+    #[test]
+    fn test_part1() {
+        let points = parse_input(include_str!("../../input-day10")).unwrap();
+        let message = part1(&points);
+        let rows: Vec<&str> = message.lines().collect();
+        assert_eq!(10, rows.len());
+        assert_eq!("######..#....#....##....#.......#.......#....#..#.......#####.", rows[0]);
+        assert_eq!("######..#....#..#....#..######..######..#....#..######..#####.", rows[9]);
+    }
+}
@@ -0,0 +1,155 @@
+#![allow(clippy::needless_borrow, clippy::ptr_arg, clippy::useless_conversion)]
+
+use aoc_core::{Answer, ParseError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf).map(create_grid)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub fn parse_input(buf: &str) -> Result<usize, ParseError> {
+    let serial = buf.trim();
+    serial
+        .parse()
+        .map_err(|_| ParseError::new(1, 1, serial, "expected a number"))
+}
+
+pub fn create_grid(input: usize) -> Vec<Vec<isize>> {
+    (0..300)
+        .into_iter()
+        .map(|x| {
+            (0..300)
+                .into_iter()
+                .map(|y| power_level(x + 1, y + 1, input))
+                .collect::<Vec<isize>>()
+        })
+        .collect()
+}
+
+fn power_level(x: usize, y: usize, serial: usize) -> isize {
+    let rack_id = x + 10;
+    let power_level = rack_id * y;
+    let power_level = power_level + serial;
+    let power_level = power_level * rack_id;
+    let pl = format!("{}", power_level);
+    let hundreds = &pl[pl.len() - 3..pl.len() - 2];
+    let hundreds: usize = hundreds.parse().unwrap();
+    hundreds as isize - 5_isize
+}
+
+pub fn part1(grid: &Vec<Vec<isize>>) -> (usize, usize) {
+    let (x, y, _) = largest_power(grid, 3);
+    (x, y)
+}
+
+pub fn part2(grid: &Vec<Vec<isize>>) -> (usize, usize, usize) {
+    use rayon::prelude::*;
+
+    let max_square = (1..300_usize)
+        .into_par_iter()
+        .map(|square_size| {
+            let lp = largest_power(grid, square_size);
+            eprintln!("square_size: {} largest {:?}", square_size, lp);
+            let (x, y, power) = lp;
+            ((x, y, square_size), power)
+        })
+        .max_by(|(_, x), (_, y)| x.cmp(y))
+        .unwrap();
+
+    let full_sum: isize = grid.iter().map(|col| col.iter().sum::<isize>()).sum();
+    eprintln!("square_size: 300 largest (1, 1, {})", full_sum);
+
+    if full_sum > max_square.1 {
+        (1, 1, 300)
+    } else {
+        let (coords, _) = max_square;
+        coords
+    }
+}
+
+fn square_coords(l: usize, h: usize, width: usize) -> impl Iterator<Item = (usize, usize)> {
+    use itertools::Itertools;
+
+    (l..l + width).cartesian_product(h..h + width)
+}
+
+fn largest_power(grid: &Vec<Vec<isize>>, square_size: usize) -> (usize, usize, isize) {
+    use itertools::Itertools;
+
+    let cap = 300 - square_size;
+    let squares = (0..cap)
+        .cartesian_product(0..cap)
+        .map(|(x, y)| (x + 1, y + 1));
+    let square_power = squares.map(|(x, y)| {
+        let coords = square_coords(x, y, square_size);
+        let total_power: isize = coords.map(|(x, y)| grid[x - 1][y - 1]).sum();
+        (x, y, total_power)
+    });
+
+    square_power
+        .max_by(|(_, _, x): &(usize, usize, isize), (_, _, y)| x.cmp(&y))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_power_level() {
+        assert_eq!(4, power_level(3, 5, 8));
+        assert_eq!(-5, power_level(122, 79, 57));
+        assert_eq!(0, power_level(217, 196, 39));
+        assert_eq!(4, power_level(101, 153, 71));
+    }
+
+    #[test]
+    fn test_part1() {
+        let grid = create_grid(18);
+        assert_eq!((33, 45), part1(&grid));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(Ok(9424), parse_input(include_str!("../../input-day11")));
+        assert_eq!(
+            Err(ParseError::new(1, 1, "12a", "expected a number")),
+            parse_input("12a\n")
+        );
+    }
+
+    #[test]
+    fn test_part1_real() {
+        let grid = create_grid(9424);
+        assert_eq!((243, 72), part1(&grid));
+    }
+
+    // #[test]
+    // fn test_part2() {
+    //     let grid = create_grid(18);
+    //     assert_eq!((90, 269, 16), part2(&grid));
+
+    //     let grid = create_grid(42);
+    //     assert_eq!((232, 251, 12), part2(&grid));
+    // }
+
+    // #[test]
+    // fn test_part2_real() {
+    //     let grid = create_grid(9424);
+    //     assert_eq!((229, 192, 11), part2(&grid));
+    // }
+}
@@ -0,0 +1,192 @@
+#![allow(clippy::bool_comparison, clippy::needless_borrow)]
+
+#[macro_use]
+extern crate nom;
+
+use aoc_core::{finish, Answer, ParseError, Solution};
+use nom::types::CompleteStr;
+use std::collections::*;
+
+pub type Patterns = HashMap<[bool; 5], bool>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = (Vec<bool>, Patterns);
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(&input.0, &input.1).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(&input.0, &input.1).into()
+    }
+}
+
+#[allow(non_upper_case_globals)]
+pub fn part1(initial_state: &[bool], patterns: &Patterns) -> isize {
+    const padding: usize = 50;
+    let mut state: Vec<bool> = vec![false; padding];
+    state.append(&mut initial_state.to_vec());
+    state.append(&mut vec![false; padding]);
+
+    let final_state = (0..20).fold(state, |acc, _| generation(&acc, patterns));
+
+    final_state
+        .iter()
+        .enumerate()
+        .map(|(i, x)| if *x { i as isize - padding as isize } else { 0 })
+        .sum()
+}
+
+#[allow(non_upper_case_globals)]
+pub fn part2(initial_state: &[bool], patterns: &Patterns) -> isize {
+    const padding: usize = 1000;
+    const n_generations: usize = 50_000_000_000;
+    let mut state: Vec<bool> = vec![false; padding];
+    state.append(&mut initial_state.to_vec());
+    state.append(&mut vec![false; padding]);
+
+    let generations = (0..n_generations).scan(state, |acc, _| {
+        let next_gen = generation(&acc, patterns);
+        *acc = next_gen.clone();
+
+        Some(next_gen)
+    });
+
+    let mut seen = HashMap::new();
+
+    let mut plant_sum = 0;
+
+    for (n, gen) in generations.enumerate() {
+        let gen_sum: isize = gen
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if *x { i as isize - padding as isize } else { 0 })
+            .sum();
+        // println!("[{}] -> {}", n, gen_sum);
+        plant_sum = gen_sum;
+        let lbounds = gen.iter().position(|x| *x == true).unwrap();
+        let rbounds = gen.iter().rposition(|x| *x == true).unwrap();
+        let pattern = gen[lbounds..=rbounds].to_vec();
+        let current_pos = lbounds as isize - padding as isize;
+        if let Some((first_pos, _first_gen)) = seen.insert(pattern.clone(), (current_pos, n)) {
+            // println!(
+            //     "convergence! position: {}, generation: {}",
+            //     first_pos, _first_gen
+            // );
+
+            let pos_diff = current_pos - first_pos;
+            let remaining_generations = n_generations - n - 1;
+            let final_pos = (pos_diff * remaining_generations as isize) + current_pos;
+
+            return pattern
+                .iter()
+                .enumerate()
+                .map(|(i, x)| if *x { i as isize + final_pos } else { 0 })
+                .sum();
+        }
+    }
+
+    plant_sum
+}
+
+fn generation(initial_state: &[bool], patterns: &Patterns) -> Vec<bool> {
+    let mut generation: Vec<bool> = Vec::with_capacity(initial_state.len());
+    generation.push(initial_state[0]);
+    generation.push(initial_state[1]);
+    for i in 2..initial_state.len() - 2 {
+        match patterns.get(&[
+            initial_state[i - 2],
+            initial_state[i - 1],
+            initial_state[i],
+            initial_state[i + 1],
+            initial_state[i + 2],
+        ]) {
+            Some(x) => generation.push(*x),
+            None => generation.push(initial_state[i]),
+        }
+    }
+    generation.push(initial_state[initial_state.len() - 2]);
+    generation.push(initial_state[initial_state.len() - 1]);
+
+    generation
+}
+
+pub fn parse_input(buf: &str) -> Result<(Vec<bool>, Patterns), ParseError> {
+    let mut lines = buf.lines().enumerate();
+    let first = lines.next().map(|(_, ln)| ln).unwrap_or("");
+    let initial_state = finish(first, 1, parse_initial_state(first.into()))?;
+    let patterns = lines
+        .filter(|(_, ln)| !ln.is_empty())
+        .map(|(i, ln)| finish(ln, i + 1, parse_pattern(ln.into())))
+        .collect::<Result<Patterns, ParseError>>()?;
+
+    Ok((initial_state, patterns))
+}
+
+named!(parse_state<CompleteStr, bool>,
+       do_parse!(
+           st: alt!(tag!(".") | tag!("#")) >>
+               (match st.as_ref() {
+                   "." => false,
+                   "#" => true,
+                   _ => unreachable!(),
+               })));
+
+named!(parse_initial_state<CompleteStr, Vec<bool>>,
+       do_parse!(
+           tag!("initial state: ") >>
+               states: many1!(parse_state) >>
+               (states)));
+
+named!(parse_pattern<CompleteStr, ([bool; 5], bool)>,
+       do_parse!(
+           states: count!(parse_state, 5) >>
+               tag!(" => ") >>
+               end_state: parse_state >>
+               (([states[0], states[1], states[2], states[3], states[4]], end_state))));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let buf = "initial state: #..#.#..##
+
+...## => #
+..#.. => x";
+        assert_eq!(
+            Err(ParseError::new(4, 10, "..#.. => x", "unrecognized entry")),
+            parse_input(buf)
+        );
+
+        let buf = "initial state: #..#.#..##
+
+...# => #";
+        assert_eq!(
+            Err(ParseError::new(3, 1, "...# => #", "wrong number of entries")),
+            parse_input(buf)
+        );
+    }
+
+    #[test]
+    fn test_part1_real() {
+        let buf = include_str!("../../input-day12");
+        let (initial_state, patterns) = parse_input(&buf).unwrap();
+        assert_eq!(4386, part1(&initial_state, &patterns));
+    }
+
+    #[test]
+    fn test_part2_real() {
+        let buf = include_str!("../../input-day12");
+        let (initial_state, patterns) = parse_input(&buf).unwrap();
+        assert_eq!(5450000001166, part2(&initial_state, &patterns));
+    }
+}
@@ -0,0 +1,197 @@
+extern crate tap;
+
+pub mod similarity;
+
+use crate::similarity::{similar_pairs, Distance};
+use aoc_core::{Answer, ParseError, Solution};
+use std::collections::{BTreeMap, HashMap};
+use tap::TapOps;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<String>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(buf))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub fn parse_input(buf: &str) -> Vec<String> {
+    buf.lines().map(|s| s.into()).collect()
+}
+
+pub fn part1(codes: &[String]) -> usize {
+    checksum(codes, &[2, 3]).product
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Checksum {
+    /// How many IDs have some letter appearing exactly `n` times, by `n`
+    pub counts: BTreeMap<usize, usize>,
+    pub product: usize,
+}
+
+/// Checksum over any set of letter multiplicities, where a letter is a
+/// grapheme cluster so e.g. "e\u{301}" counts once rather than as two chars
+pub fn checksum<S: AsRef<str>>(codes: &[S], multiplicities: &[usize]) -> Checksum {
+    let char_counts: Vec<CharCounts> = codes.iter().map(|c| char_counts(c.as_ref())).collect();
+
+    let counts: BTreeMap<usize, usize> = multiplicities
+        .iter()
+        .map(|&n| {
+            let with_n = char_counts.iter().filter(|hm| has_count(hm, n)).count();
+            (n, with_n)
+        })
+        .collect();
+    let product = counts.values().product();
+
+    Checksum { counts, product }
+}
+
+pub fn char_counts(code: &str) -> CharCounts<'_> {
+    code.graphemes(true).fold(CharCounts::new(), |acc, g| {
+        acc.tap(|a| *a.entry(g).and_modify(|e| *e += 1).or_insert(1))
+    })
+}
+
+/// Common letters of the first pair (by input position) of IDs differing
+/// in exactly one position
+pub fn part2(codes: &[String]) -> Option<String> {
+    part2_all(codes).into_iter().next().map(|m| m.common)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct OneOff {
+    /// Input positions of the two IDs, `left < right`
+    pub left: usize,
+    pub right: usize,
+    /// Position of the one letter that differs
+    pub index: usize,
+    pub common: String,
+}
+
+/// Every pair of IDs differing in exactly one position, ordered by the
+/// input positions of the pair
+pub fn part2_all<S: AsRef<str>>(codes: &[S]) -> Vec<OneOff> {
+    similar_pairs(codes, Distance::Hamming(1))
+        .into_iter()
+        .filter(|m| m.distance == 1)
+        .map(|m| {
+            let lhs = codes[m.left].as_ref().chars();
+            let rhs = codes[m.right].as_ref().chars();
+            let index = lhs.zip(rhs).position(|(l, r)| l != r).unwrap();
+            OneOff {
+                left: m.left,
+                right: m.right,
+                index,
+                common: m.common,
+            }
+        })
+        .collect()
+}
+
+#[inline]
+fn has_count(hm: &CharCounts, n: usize) -> bool {
+    hm.values().any(|count| *count == n)
+}
+
+pub type CharCounts<'a> = HashMap<&'a str, usize>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day1() {
+        let buf = include_str!("../../input-day2");
+        let codes: Vec<String> = buf.lines().map(|l| l.into()).collect();
+
+        assert_eq!(4693, part1(&codes));
+    }
+
+    #[test]
+    fn test_checksum() {
+        let codes = [
+            "abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab",
+        ];
+        let sum = checksum(&codes, &[2, 3]);
+        assert_eq!(
+            vec![(2, 4), (3, 3)],
+            sum.counts.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(12, sum.product);
+
+        let sum = checksum(&codes, &[1, 2, 3, 4]);
+        assert_eq!(
+            vec![(1, 6), (2, 4), (3, 3), (4, 0)],
+            sum.counts.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(0, sum.product);
+    }
+
+    #[test]
+    fn test_checksum_graphemes() {
+        // "e" plus a combining acute accent is one letter, not an "e"
+        let codes = ["e\u{301}e\u{301}e", "\u{1f1ec}\u{1f1e7}\u{1f1ec}\u{1f1e7}"];
+        let counts = char_counts(codes[0]);
+        assert_eq!(Some(&2), counts.get("e\u{301}"));
+        assert_eq!(Some(&1), counts.get("e"));
+
+        let sum = checksum(&codes, &[1, 2]);
+        assert_eq!(
+            vec![(1, 1), (2, 2)],
+            sum.counts.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(2, sum.product);
+    }
+
+    #[test]
+    fn test_part2_all() {
+        let codes = [
+            "abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz",
+        ];
+        assert_eq!(
+            vec![OneOff {
+                left: 1,
+                right: 4,
+                index: 2,
+                common: "fgij".into()
+            }],
+            part2_all(&codes)
+        );
+
+        let codes = ["abcd", "abce", "xbcd", "abcd", "abde"];
+        let pairs: Vec<(usize, usize, usize)> = part2_all(&codes)
+            .iter()
+            .map(|m| (m.left, m.right, m.index))
+            .collect();
+        assert_eq!(
+            vec![(0, 1, 3), (0, 2, 0), (1, 3, 3), (1, 4, 2), (2, 3, 0)],
+            pairs
+        );
+
+        let codes: Vec<String> = codes.iter().map(|&c| c.into()).collect();
+        assert_eq!(Some("abc".into()), part2(&codes));
+        assert_eq!(None, part2(&codes[3..4]));
+    }
+
+    #[test]
+    fn test_day2() {
+        let buf = include_str!("../../input-day2");
+        let codes: Vec<String> = buf.lines().map(|l| l.into()).collect();
+
+        let expected = "pebjqsalrdnckzfihvtxysomg";
+        assert_eq!(Some(expected.into()), part2(&codes));
+    }
+}
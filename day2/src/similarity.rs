@@ -0,0 +1,230 @@
+// Finds every pair of box IDs within some edit distance of each other
+// without comparing every ID against every other one.
+//
+// Both distances use the pigeonhole principle: split an ID into `max + 1`
+// segments and any ID within `max` edits of it has to contain at least one
+// of those segments untouched. For Hamming distance the segment stays put,
+// for Levenshtein it can only have shifted by up to `max` positions. IDs
+// are indexed by their segments, so only IDs sharing a segment get compared.
+
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Distance {
+    /// Same length, differing in at most this many positions
+    Hamming(usize),
+    /// At most this many insertions, deletions or substitutions
+    Levenshtein(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    /// Input positions of the two IDs, `left < right`
+    pub left: usize,
+    pub right: usize,
+    pub distance: usize,
+    /// Letters the two IDs have in common, in order
+    pub common: String,
+}
+
+/// All pairs of `ids` within `distance` of each other, ordered by the
+/// input positions of the pair
+pub fn similar_pairs<S: AsRef<str>>(ids: &[S], distance: Distance) -> Vec<Match> {
+    let ids: Vec<Vec<char>> = ids.iter().map(|id| id.as_ref().chars().collect()).collect();
+    let (max, shift) = match distance {
+        Distance::Hamming(max) => (max, 0),
+        Distance::Levenshtein(max) => (max, max),
+    };
+
+    // (id length, segment number, segment) -> ids with that segment
+    let mut index: HashMap<(usize, usize, &[char]), Vec<usize>> = HashMap::new();
+    let mut matches = Vec::new();
+
+    for (right, id) in ids.iter().enumerate() {
+        let mut candidates: BTreeSet<usize> = BTreeSet::new();
+        let lengths = id.len().saturating_sub(shift)..=id.len() + shift;
+        for len in lengths {
+            for (n, (start, end)) in segments(len, max).enumerate() {
+                let seg_len = end - start;
+                let from = start.saturating_sub(shift);
+                let to = (start + shift).min(id.len().saturating_sub(seg_len));
+                for pos in from..=to {
+                    if pos + seg_len > id.len() {
+                        break;
+                    }
+                    if let Some(lefts) = index.get(&(len, n, &id[pos..pos + seg_len])) {
+                        candidates.extend(lefts);
+                    }
+                }
+            }
+        }
+
+        for left in candidates {
+            let other = &ids[left];
+            let found = match distance {
+                Distance::Hamming(_) => hamming(other, id),
+                Distance::Levenshtein(_) => levenshtein(other, id),
+            };
+            if let Some((distance, common)) = found.filter(|(d, _)| *d <= max) {
+                matches.push(Match {
+                    left,
+                    right,
+                    distance,
+                    common,
+                });
+            }
+        }
+
+        for (n, (start, end)) in segments(id.len(), max).enumerate() {
+            index
+                .entry((id.len(), n, &id[start..end]))
+                .or_default()
+                .push(right);
+        }
+    }
+
+    matches.sort_by_key(|m| (m.left, m.right));
+    matches
+}
+
+// Splits `len` into `max + 1` nearly even [start, end) ranges
+fn segments(len: usize, max: usize) -> impl Iterator<Item = (usize, usize)> {
+    let parts = max + 1;
+    (0..parts).map(move |n| (n * len / parts, (n + 1) * len / parts))
+}
+
+fn hamming(lhs: &[char], rhs: &[char]) -> Option<(usize, String)> {
+    if lhs.len() != rhs.len() {
+        return None;
+    }
+    let distance = lhs.iter().zip(rhs).filter(|(l, r)| l != r).count();
+    let common = lhs
+        .iter()
+        .zip(rhs)
+        .filter(|(l, r)| l == r)
+        .map(|(l, _)| l)
+        .collect();
+    Some((distance, common))
+}
+
+// Edit distance plus the letters an optimal alignment keeps
+fn levenshtein(lhs: &[char], rhs: &[char]) -> Option<(usize, String)> {
+    let (n, m) = (lhs.len(), rhs.len());
+    let mut dist = vec![vec![0; m + 1]; n + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    dist[0] = (0..=m).collect();
+    for i in 1..=n {
+        for j in 1..=m {
+            let sub = if lhs[i - 1] == rhs[j - 1] { 0 } else { 1 };
+            dist[i][j] = (dist[i - 1][j - 1] + sub)
+                .min(dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1);
+        }
+    }
+
+    let mut common = Vec::new();
+    let (mut i, mut j) = (n, m);
+    while i > 0 && j > 0 {
+        if lhs[i - 1] == rhs[j - 1] && dist[i][j] == dist[i - 1][j - 1] {
+            common.push(lhs[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if dist[i][j] == dist[i - 1][j - 1] + 1 {
+            i -= 1;
+            j -= 1;
+        } else if dist[i][j] == dist[i - 1][j] + 1 {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    Some((dist[n][m], common.into_iter().rev().collect()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: [&str; 7] = [
+        "abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz",
+    ];
+
+    fn pairs(matches: &[Match]) -> Vec<(usize, usize, usize, &str)> {
+        matches
+            .iter()
+            .map(|m| (m.left, m.right, m.distance, m.common.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_hamming() {
+        let matches = similar_pairs(&EXAMPLE, Distance::Hamming(1));
+        assert_eq!(vec![(1, 4, 1, "fgij")], pairs(&matches));
+
+        let matches = similar_pairs(&EXAMPLE, Distance::Hamming(2));
+        assert_eq!(vec![(0, 5, 2, "ace"), (1, 4, 1, "fgij")], pairs(&matches));
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        let ids = ["kitten", "sitting", "kitchen", "mitten", "kitten"];
+        let matches = similar_pairs(&ids, Distance::Levenshtein(1));
+        assert_eq!(
+            vec![(0, 3, 1, "itten"), (0, 4, 0, "kitten"), (3, 4, 1, "itten")],
+            pairs(&matches)
+        );
+
+        let matches = similar_pairs(&ids, Distance::Levenshtein(3));
+        assert!(pairs(&matches).contains(&(0, 1, 3, "ittn")));
+        assert!(pairs(&matches).contains(&(0, 2, 2, "kiten")));
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let ids: Vec<String> = (0..300_u32)
+            .map(|n| {
+                let n = n.wrapping_mul(2_654_435_761);
+                (0..4 + n % 3)
+                    .map(|i| (b'a' + ((n >> (i * 3)) % 3) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        let chars: Vec<Vec<char>> = ids.iter().map(|id| id.chars().collect()).collect();
+
+        for &distance in &[Distance::Hamming(1), Distance::Levenshtein(2)] {
+            let mut expected = Vec::new();
+            for right in 0..ids.len() {
+                for left in 0..right {
+                    let found = match distance {
+                        Distance::Hamming(_) => hamming(&chars[left], &chars[right]),
+                        Distance::Levenshtein(_) => levenshtein(&chars[left], &chars[right]),
+                    };
+                    let max = match distance {
+                        Distance::Hamming(max) | Distance::Levenshtein(max) => max,
+                    };
+                    if found.filter(|(d, _)| *d <= max).is_some() {
+                        expected.push((left, right));
+                    }
+                }
+            }
+            expected.sort();
+            let found: Vec<(usize, usize)> = similar_pairs(&ids, distance)
+                .iter()
+                .map(|m| (m.left, m.right))
+                .collect();
+            assert_eq!(expected, found, "{:?}", distance);
+        }
+    }
+
+    #[test]
+    fn test_real() {
+        let buf = include_str!("../../input-day2");
+        let ids: Vec<&str> = buf.lines().collect();
+        let matches = similar_pairs(&ids, Distance::Hamming(1));
+        assert_eq!(1, matches.len());
+        assert_eq!("pebjqsalrdnckzfihvtxysomg", matches[0].common);
+    }
+}
@@ -0,0 +1,24 @@
+// Renders the day3 claims to day3-viz.png
+//
+//   day3-viz [<input path> | -]
+
+use std::process;
+
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "input-day3".into());
+    if let Err(e) = render(&path) {
+        eprintln!("error: {}: {}", path, e);
+        process::exit(1);
+    }
+}
+
+fn render(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let buf = aoc_core::read_input(path)?;
+    let boxes = day3::parse_input(&buf)?;
+
+    println!("saving day3-viz.png");
+    day3::render::render(&boxes)?.save("day3-viz.png")?;
+    Ok(())
+}
@@ -0,0 +1,252 @@
+// Overlap detection for claims of any size or position, sweeping a line
+// across x rather than filling in a grid. Claims are half-open, so one
+// ending at x = 5 doesn't touch one starting at x = 5.
+
+use crate::Rect;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overlaps {
+    /// Square inches covered by two or more claims
+    pub area: usize,
+    /// Every pair of overlapping claim IDs, as (lower, higher), sorted
+    pub pairs: Vec<(usize, usize)>,
+    /// IDs of the claims that overlap nothing, in input order
+    pub intact: Vec<usize>,
+}
+
+pub fn overlaps(rects: &[Rect]) -> Overlaps {
+    let (area, index_pairs) = sweep(rects, 2);
+
+    let mut overlapped = vec![false; rects.len()];
+    let mut pairs: Vec<(usize, usize)> = index_pairs
+        .into_iter()
+        .map(|(a, b)| {
+            overlapped[a] = true;
+            overlapped[b] = true;
+            let (a, b) = (rects[a].id, rects[b].id);
+            (a.min(b), a.max(b))
+        })
+        .collect();
+    pairs.sort_unstable();
+
+    let intact = rects
+        .iter()
+        .zip(overlapped)
+        .filter(|(_, o)| !o)
+        .map(|(r, _)| r.id)
+        .collect();
+
+    Overlaps {
+        area,
+        pairs,
+        intact,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClaimStats {
+    pub id: usize,
+    /// How many other claims this one overlaps
+    pub overlapping: usize,
+    /// Square inches of this claim that some other claim also covers
+    pub overlapped_area: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// IDs of the claims that overlap nothing, in input order
+    pub intact: Vec<usize>,
+    /// One entry per claim, in input order
+    pub claims: Vec<ClaimStats>,
+}
+
+pub fn analyze(rects: &[Rect]) -> Analysis {
+    let (_, pairs) = sweep(rects, 2);
+
+    let mut shared: Vec<Vec<Rect>> = vec![Vec::new(); rects.len()];
+    for (a, b) in pairs {
+        let common = intersection(&rects[a], &rects[b]);
+        shared[a].push(common.clone());
+        shared[b].push(common);
+    }
+
+    let claims: Vec<ClaimStats> = rects
+        .iter()
+        .zip(shared)
+        .map(|(r, shared)| ClaimStats {
+            id: r.id,
+            overlapping: shared.len(),
+            overlapped_area: sweep(&shared, 1).0,
+        })
+        .collect();
+    let intact = claims
+        .iter()
+        .filter(|c| c.overlapping == 0)
+        .map(|c| c.id)
+        .collect();
+
+    Analysis { intact, claims }
+}
+
+fn intersection(a: &Rect, b: &Rect) -> Rect {
+    let (x, y) = (a.x.max(b.x), a.y.max(b.y));
+    Rect {
+        id: a.id,
+        x,
+        y,
+        width: (a.x + a.width).min(b.x + b.width) - x,
+        height: (a.y + a.height).min(b.y + b.height) - y,
+    }
+}
+
+// Area covered by at least `min_depth` of the rects, plus the index pairs
+// of every two rects that overlap
+fn sweep(rects: &[Rect], min_depth: isize) -> (usize, Vec<(usize, usize)>) {
+    // (x, is_start, rect), so at the same x ends come before starts
+    let mut events: Vec<(usize, bool, usize)> = rects
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| r.width > 0 && r.height > 0)
+        .flat_map(|(i, r)| vec![(r.x, true, i), (r.x + r.width, false, i)])
+        .collect();
+    events.sort_unstable();
+
+    let mut active: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+    let mut area = 0;
+    let mut last_x = 0;
+
+    for (x, is_start, i) in events {
+        if x > last_x {
+            area += covered(rects, &active, min_depth) * (x - last_x);
+            last_x = x;
+        }
+
+        if !is_start {
+            active.retain(|&a| a != i);
+            continue;
+        }
+        let r = &rects[i];
+        for &a in active.iter() {
+            let other = &rects[a];
+            if r.y < other.y + other.height && other.y < r.y + r.height {
+                pairs.push((a, i));
+            }
+        }
+        active.push(i);
+    }
+
+    (area, pairs)
+}
+
+// Length along y covered by at least `min_depth` of the active rects
+fn covered(rects: &[Rect], active: &[usize], min_depth: isize) -> usize {
+    let mut edges: Vec<(usize, isize)> = active
+        .iter()
+        .flat_map(|&a| {
+            let r = &rects[a];
+            vec![(r.y, 1), (r.y + r.height, -1)]
+        })
+        .collect();
+    edges.sort_unstable();
+
+    let mut depth = 0;
+    let mut last_y = 0;
+    let mut covered = 0;
+    for (y, change) in edges {
+        if depth >= min_depth {
+            covered += y - last_y;
+        }
+        depth += change;
+        last_y = y;
+    }
+    covered
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rect(id: usize, x: usize, y: usize, width: usize, height: usize) -> Rect {
+        Rect {
+            id,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        let rects = vec![
+            rect(1, 1, 3, 4, 4),
+            rect(2, 3, 1, 4, 4),
+            rect(3, 5, 5, 2, 2),
+        ];
+        assert_eq!(
+            Overlaps {
+                area: 4,
+                pairs: vec![(1, 2)],
+                intact: vec![3],
+            },
+            overlaps(&rects)
+        );
+    }
+
+    #[test]
+    fn test_unbounded() {
+        // well past the old 1000x1000 grid, touching edges don't overlap
+        let rects = vec![
+            rect(1, 5000, 5000, 10, 10),
+            rect(2, 5005, 5005, 10, 10),
+            rect(3, 5000, 5010, 10, 10),
+            rect(4, 5008, 5002, 1, 1),
+            rect(5, 2_000_000, 0, 3, 3),
+        ];
+        let found = overlaps(&rects);
+        assert_eq!(25 + 1 + 25, found.area);
+        assert_eq!(vec![(1, 2), (1, 4), (2, 3)], found.pairs);
+        assert_eq!(vec![5], found.intact);
+    }
+
+    #[test]
+    fn test_analyze() {
+        let rects = vec![
+            rect(1, 0, 0, 4, 4),
+            rect(2, 2, 2, 4, 4),
+            rect(3, 3, 0, 2, 2),
+            rect(4, 10, 10, 1, 1),
+            rect(5, 11, 10, 1, 1),
+        ];
+        let stats: Vec<(usize, usize, usize)> = analyze(&rects)
+            .claims
+            .iter()
+            .map(|c| (c.id, c.overlapping, c.overlapped_area))
+            .collect();
+        // #1 loses 2x2 to #2 and 1x2 to #3, sharing nothing between them
+        assert_eq!(
+            vec![(1, 2, 6), (2, 1, 4), (3, 1, 2), (4, 0, 0), (5, 0, 0)],
+            stats
+        );
+        assert_eq!(vec![4, 5], analyze(&rects).intact);
+
+        let rects = vec![rect(1, 0, 0, 2, 2), rect(2, 1, 1, 2, 2)];
+        assert_eq!(Vec::<usize>::new(), analyze(&rects).intact);
+        assert_eq!(None, crate::part2(&rects));
+    }
+
+    #[test]
+    fn test_real() {
+        let buf = include_str!("../../input-day3");
+        let rects = crate::parse_input(buf).unwrap();
+        let found = overlaps(&rects);
+        assert_eq!(104439, found.area);
+        assert_eq!(vec![701], found.intact);
+
+        let analysis = analyze(&rects);
+        assert_eq!(vec![701], analysis.intact);
+        let double_counted: usize = analysis.claims.iter().map(|c| c.overlapped_area).sum();
+        assert!(double_counted >= found.area * 2);
+    }
+}
@@ -0,0 +1,110 @@
+#[macro_use]
+extern crate nom;
+
+pub mod claims;
+pub mod render;
+
+use crate::claims::overlaps;
+use aoc_core::{finish, parse_str, Answer, ParseError, Solution};
+use nom::digit;
+use nom::types::CompleteStr;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<Rect>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub fn parse_input(buf: &str) -> Result<Vec<Rect>, ParseError> {
+    buf.lines()
+        .enumerate()
+        .map(|(i, l)| finish(l, i + 1, parse_box(l.into())))
+        .collect()
+}
+
+pub fn part1(boxes: &[Rect]) -> usize {
+    overlaps(boxes).area
+}
+
+/// The first claim, in input order, that doesn't overlap any other
+pub fn part2(boxes: &[Rect]) -> Option<usize> {
+    overlaps(boxes).intact.first().copied()
+}
+
+named!(number<CompleteStr, usize>, map_res!(digit, parse_str));
+
+named!(parse_box<CompleteStr, Rect>,
+do_parse!(
+    tag!("#") >>
+        id: number >>
+        tag!(" @ ") >>
+        x: number >>
+        tag!(",") >>
+        y: number >>
+        tag!(": ") >>
+        width: number >>
+        tag!("x") >>
+        height: number >>
+        (
+            Rect{
+                id,
+                x,
+                y,
+                width,
+                height,
+            }
+        )
+));
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rect {
+    pub id: usize,
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn get_boxes() -> Vec<Rect> {
+        let buf = include_str!("../../input-day3");
+        parse_input(buf).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let buf = "#1 @ 1,3: 4x4\n#2 @ 3,x: 4x4";
+        assert_eq!(
+            Err(ParseError::new(2, 8, "#2 @ 3,x: 4x4", "expected a number")),
+            parse_input(buf)
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let boxes = get_boxes();
+        assert_eq!(104439, part1(&boxes));
+    }
+
+    #[test]
+    fn test_part2() {
+        let boxes = get_boxes();
+        assert_eq!(Some(701), part2(&boxes));
+    }
+}
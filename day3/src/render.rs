@@ -0,0 +1,182 @@
+// Draws the fabric with each claim in its own color, square inches claimed
+// more than once in red and the intact claim from `part2` outlined in white
+
+use crate::{part2, Rect};
+use image::{ImageBuffer, Rgb, RgbImage};
+use std::fmt;
+
+const BACKGROUND: Rgb<u8> = Rgb { data: [0, 0, 0] };
+const OVERLAP: Rgb<u8> = Rgb { data: [255, 0, 0] };
+const OUTLINE: Rgb<u8> = Rgb {
+    data: [255, 255, 255],
+};
+
+/// The most square inches `render` will draw, about 200MB of image. The
+/// puzzle's fabric is a million.
+pub const MAX_PIXELS: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TooLarge {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl fmt::Display for TooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{} is too big to render", self.width, self.height)
+    }
+}
+
+impl std::error::Error for TooLarge {}
+
+/// One pixel per square inch, cropped to the claims' bounding box. Refuses
+/// anything over `MAX_PIXELS`.
+pub fn render(boxes: &[Rect]) -> Result<RgbImage, TooLarge> {
+    let left = boxes.iter().map(|r| r.x).min().unwrap_or(0);
+    let top = boxes.iter().map(|r| r.y).min().unwrap_or(0);
+    let width = boxes
+        .iter()
+        .map(|r| r.x + r.width - left)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let height = boxes
+        .iter()
+        .map(|r| r.y + r.height - top)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let pixels = match width.checked_mul(height) {
+        Some(pixels) if pixels <= MAX_PIXELS => pixels,
+        _ => return Err(TooLarge { width, height }),
+    };
+
+    let mut img = ImageBuffer::from_pixel(width as u32, height as u32, BACKGROUND);
+    let mut claimed = vec![false; pixels];
+    for (i, r) in boxes.iter().enumerate() {
+        let color = claim_color(i);
+        for x in r.x - left..r.x + r.width - left {
+            for y in r.y - top..r.y + r.height - top {
+                let seen = &mut claimed[y * width + x];
+                let px = if *seen { OVERLAP } else { color };
+                *seen = true;
+                img.put_pixel(x as u32, y as u32, px);
+            }
+        }
+    }
+
+    if let Some(r) = part2(boxes).and_then(|id| boxes.iter().find(|r| r.id == id)) {
+        for x in r.x..r.x + r.width {
+            for y in r.y..r.y + r.height {
+                let edge =
+                    x == r.x || y == r.y || x + 1 == r.x + r.width || y + 1 == r.y + r.height;
+                if edge {
+                    img.put_pixel((x - left) as u32, (y - top) as u32, OUTLINE);
+                }
+            }
+        }
+    }
+
+    Ok(img)
+}
+
+// Steps the hue by the golden ratio so neighbouring claims never end up
+// with similar colors, kept away from the red used for overlaps
+fn claim_color(i: usize) -> Rgb<u8> {
+    let hue = 0.1 + (i as f64 * 0.618_033_988_75).fract() * 0.8;
+    let (s, v) = (0.6, 0.85);
+
+    let h = hue * 6.0;
+    let c = v * s;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as usize {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = v - c;
+    let to_u8 = |n: f64| ((n + m) * 255.0) as u8;
+    Rgb([to_u8(r), to_u8(g), to_u8(b)])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let boxes = crate::parse_input("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2").unwrap();
+        let img = render(&boxes).unwrap();
+        // cropped to start at 1,1
+        assert_eq!((6, 6), img.dimensions());
+
+        assert_eq!(&BACKGROUND, img.get_pixel(0, 0));
+        assert_eq!(&claim_color(0), img.get_pixel(0, 2));
+        assert_eq!(&claim_color(1), img.get_pixel(5, 0));
+        assert_eq!(&OVERLAP, img.get_pixel(2, 2));
+        assert_eq!(&OVERLAP, img.get_pixel(3, 3));
+        // #3 is 2x2, so it's all outline
+        assert_eq!(&OUTLINE, img.get_pixel(4, 4));
+        assert_eq!(&OUTLINE, img.get_pixel(5, 5));
+    }
+
+    #[test]
+    fn test_render_bounding_box() {
+        let boxes = crate::parse_input("#1 @ 2000000,5000: 3x2\n#2 @ 2000002,5001: 2x2").unwrap();
+        let img = render(&boxes).unwrap();
+        assert_eq!((4, 3), img.dimensions());
+        assert_eq!(&claim_color(0), img.get_pixel(0, 0));
+        assert_eq!(&BACKGROUND, img.get_pixel(0, 2));
+        assert_eq!(&OVERLAP, img.get_pixel(2, 1));
+        assert_eq!(&claim_color(1), img.get_pixel(3, 2));
+
+        assert_eq!((1, 1), render(&[]).unwrap().dimensions());
+    }
+
+    #[test]
+    fn test_render_too_large() {
+        let over = crate::parse_input("#1 @ 5,5: 8192x8193").unwrap();
+        assert!(render(&over).is_err());
+
+        let wide = crate::parse_input("#1 @ 0,0: 1x1\n#2 @ 5000000000,0: 1x1").unwrap();
+        assert_eq!(
+            TooLarge {
+                width: 5_000_000_001,
+                height: 1
+            },
+            render(&wide).unwrap_err()
+        );
+
+        let apart = crate::parse_input("#1 @ 0,0: 1x1\n#2 @ 200000,200000: 1x1").unwrap();
+        assert_eq!(
+            TooLarge {
+                width: 200_001,
+                height: 200_001
+            },
+            render(&apart).unwrap_err()
+        );
+
+        let huge = u32::MAX as usize;
+        let tall = crate::parse_input(&format!("#1 @ 0,0: {0}x1\n#2 @ 0,0: 1x{0}", huge)).unwrap();
+        assert_eq!(
+            TooLarge {
+                width: huge,
+                height: huge
+            },
+            render(&tall).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_claim_colors() {
+        let colors: Vec<Rgb<u8>> = (0..20).map(claim_color).collect();
+        for (i, c) in colors.iter().enumerate() {
+            assert_ne!(&OVERLAP, c);
+            assert!(!colors[i + 1..].contains(c));
+        }
+    }
+}
@@ -0,0 +1,200 @@
+// The puzzle's day-by-minute chart of the midnight hour, one row per shift:
+//
+// Date   ID   Minute
+//             000000000011111111112222222222333333333344444444445555555555
+//             012345678901234567890123456789012345678901234567890123456789
+// 11-01  #10  .....####################.....#########################.....
+
+use crate::report::SleepReport;
+use crate::validate::{validate_lenient, Shift};
+use crate::Log;
+use chrono::prelude::*;
+use std::fmt::Write;
+
+// Heatmap shades from least to most often asleep, never asleep is '.'
+const SHADES: &[u8] = b":-=+*#%@";
+
+/// The chart for the leniently validated logs, followed by a heatmap of how
+/// often each guard is asleep at each minute if `with_heatmap` is set
+pub fn render(logs: &[Log], with_heatmap: bool) -> String {
+    let shifts = validate_lenient(logs).shifts;
+    let mut out = timeline(&shifts);
+    if with_heatmap {
+        let report = SleepReport::from_shifts(&shifts, Default::default());
+        out.push('\n');
+        out.push_str(&heatmap(&report));
+    }
+    out
+}
+
+pub fn timeline(shifts: &[Shift]) -> String {
+    let width = id_width(shifts.iter().map(|s| s.guard.as_str()));
+    let mut out = format!("Date   {:<w$}  Minute\n", "ID", w = width);
+    out.push_str(&ruler(width + 9, 0..60));
+
+    for shift in shifts {
+        // guards starting before midnight are on the next day's row
+        let date = if shift.begins.hour() == 0 {
+            shift.begins.date()
+        } else {
+            shift.begins.date().succ()
+        };
+        let strip: String = (0..60)
+            .map(|m| {
+                let minute = date.and_hms(0, m, 0);
+                if shift.naps.iter().any(|nap| nap.contains(&minute)) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        let id = format!("#{}", shift.guard);
+        writeln!(
+            out,
+            "{}  {:<w$}  {}",
+            date.format("%m-%d"),
+            id,
+            strip,
+            w = width
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// One row per guard across the report's window, shading each minute by
+/// how many shifts they were asleep for it relative to the sleepiest minute
+/// of any guard
+pub fn heatmap(report: &SleepReport) -> String {
+    let width = id_width(report.guards.keys().map(String::as_str)).max("Guard".len());
+    let mut out = format!("{:<w$}  Minute\n", "Guard", w = width);
+    let window = report.window;
+    let minutes = (0..window.minutes).map(|b| window.time_of(b).minute());
+    out.push_str(&ruler(width + 2, minutes));
+
+    let most = report
+        .guards
+        .values()
+        .flat_map(|sleep| sleep.histogram.iter().cloned())
+        .max()
+        .unwrap_or(0);
+    for (guard, sleep) in &report.guards {
+        let strip: String = sleep
+            .histogram
+            .iter()
+            .map(|&freq| {
+                if freq == 0 {
+                    '.'
+                } else {
+                    let shade = (freq * SHADES.len()).div_ceil(most);
+                    SHADES[shade - 1] as char
+                }
+            })
+            .collect();
+        let id = format!("#{}", guard);
+        writeln!(
+            out,
+            "{:<w$}  {}  {} min, {} shifts",
+            id,
+            strip,
+            sleep.total_minutes,
+            sleep.shifts,
+            w = width
+        )
+        .unwrap();
+    }
+    out
+}
+
+// Width of the widest `#id`, and at least wide enough for the heading
+fn id_width<'a>(guards: impl Iterator<Item = &'a str>) -> usize {
+    guards
+        .map(|g| g.len() + 1)
+        .max()
+        .unwrap_or(0)
+        .max("ID".len())
+}
+
+// The two lines of minute numbers over the strips
+fn ruler(indent: usize, minutes: impl Iterator<Item = u32> + Clone) -> String {
+    let digit = |d: u32| (b'0' + d as u8) as char;
+    let tens: String = minutes.clone().map(|m| digit(m / 10)).collect();
+    let units: String = minutes.map(|m| digit(m % 10)).collect();
+    format!("{:i$}{}\n{:i$}{}\n", "", tens, "", units, i = indent)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validate::validate_strict;
+
+    const EXAMPLE: &str = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+
+    #[test]
+    fn test_timeline() {
+        let logs = crate::parse_input(EXAMPLE).unwrap();
+        let expected = "Date   ID   Minute
+            000000000011111111112222222222333333333344444444445555555555
+            012345678901234567890123456789012345678901234567890123456789
+11-01  #10  .....####################.....#########################.....
+11-02  #99  ........................................##########..........
+11-03  #10  ........................#####...............................
+11-04  #99  ....................................##########..............
+11-05  #99  .............................................##########.....
+";
+        assert_eq!(expected, render(&logs, false));
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let logs = crate::parse_input(EXAMPLE).unwrap();
+        let chart = render(&logs, true);
+        let rows: Vec<&str> = chart.lines().skip(12).collect();
+        assert_eq!(
+            vec![
+                "#10    .....===================#====.=========================.....  50 min, 2 shifts",
+                "#99    ....................................====#####@####=====.....  30 min, 3 shifts",
+            ],
+            rows
+        );
+        assert!(chart.lines().nth(9).unwrap().starts_with("Guard  Minute"));
+    }
+
+    #[test]
+    fn test_heatmap_window() {
+        let logs = crate::parse_input(
+            "[1518-11-01 23:30] Guard #10 begins shift
+[1518-11-01 23:58] falls asleep
+[1518-11-02 00:01] wakes up",
+        )
+        .unwrap();
+        let shifts = validate_strict(&logs).unwrap().shifts;
+        let window = crate::report::Window::new(NaiveTime::from_hms(23, 57, 0), 5);
+        let report = SleepReport::from_shifts(&shifts, window);
+        assert_eq!(
+            "Guard  Minute
+       55500
+       78901
+#10    .@@@.  3 min, 1 shifts
+",
+            heatmap(&report)
+        );
+    }
+}
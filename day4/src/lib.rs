@@ -0,0 +1,209 @@
+#[macro_use]
+extern crate nom;
+extern crate chrono;
+
+pub mod chart;
+pub mod report;
+pub mod validate;
+
+use aoc_core::{finish, Answer, ParseError, Solution};
+use chrono::prelude::*;
+use nom::digit;
+use nom::types::CompleteStr;
+use report::SleepReport;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Log>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub fn parse_input(buf: &str) -> Result<Vec<Log>, ParseError> {
+    buf.lines()
+        .enumerate()
+        .map(|(i, l)| finish(l, i + 1, parse_log(l.into())))
+        .collect()
+}
+
+// A = guard with most minutes asleep
+// B = which minute that guard spent asleep most
+// A*B
+pub fn part1(logs: &[Log]) -> Option<usize> {
+    let report = SleepReport::new(logs);
+    let (guard, sleep) = report.sleepiest()?;
+    let (minute, _) = sleep.most_likely_minute()?;
+    Some(guard.parse::<usize>().ok()? * minute)
+}
+
+pub fn part2(logs: &[Log]) -> Option<usize> {
+    let report = SleepReport::new(logs);
+    let (guard, minute, _) = report.most_consistent()?;
+    Some(guard.parse::<usize>().ok()? * minute)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GuardState {
+    BeginShift(String),
+    Asleep,
+    Wake,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Log {
+    pub ts: DateTime<Utc>,
+    pub state: GuardState,
+}
+
+named!(
+    parse_sleep<CompleteStr, GuardState>,
+    do_parse!(tag!("falls asleep") >> (GuardState::Asleep))
+);
+
+named!(
+    parse_wake<CompleteStr, GuardState>,
+    do_parse!(tag!("wakes up") >> (GuardState::Wake))
+);
+
+named!(
+    parse_shift<CompleteStr, GuardState>,
+    do_parse!(
+        tag!("Guard #") >> num: digit >> tag!(" begins shift") >> (GuardState::BeginShift(num.as_ref().into()))
+    )
+);
+
+named!(
+    parse_log<CompleteStr, Log>,
+    do_parse!(
+        tag!("[")
+            >> ts: map_res!(take!(16), |dt: CompleteStr| Utc.datetime_from_str(&dt, "%Y-%m-%d %H:%M"))
+            >> tag!("] ")
+            >> state: alt!(parse_sleep | parse_wake | parse_shift)
+            >> (Log { ts, state })
+    )
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_log() {
+        assert_eq!(
+            parse_log("[1518-08-21 00:39] wakes up".into()),
+            Ok((
+                "".into(),
+                Log {
+                    ts: Utc.ymd(1518, 8, 21).and_hms(0, 39, 0),
+                    state: GuardState::Wake,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let buf = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-13-01 00:25] wakes up";
+        assert_eq!(
+            Err(ParseError::new(
+                3,
+                2,
+                "[1518-13-01 00:25] wakes up",
+                "invalid value"
+            )),
+            parse_input(buf)
+        );
+
+        let buf = "[1518-11-01 00:05] dozes off";
+        assert_eq!(
+            Err(ParseError::new(1, 20, buf, "unrecognized entry")),
+            parse_input(buf)
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+        let logs: Vec<Log> = input
+            .lines()
+            .map(|l| parse_log(l.into()).unwrap().1)
+            .collect();
+        assert_eq!(Some(10 * 24), part1(&logs));
+    }
+
+    #[test]
+    fn test_real_part1() {
+        let buf = include_str!("../../input-day4");
+        let logs: Vec<Log> = buf
+            .lines()
+            .map(|l| parse_log(l.into()).unwrap().1)
+            .collect();
+        assert_eq!(Some(77084), part1(&logs));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+        let logs: Vec<Log> = input
+            .lines()
+            .map(|l| parse_log(l.into()).unwrap().1)
+            .collect();
+        assert_eq!(Some(99 * 45), part2(&logs));
+    }
+
+    #[test]
+    fn test_real_part2() {
+        let buf = include_str!("../../input-day4");
+        let logs: Vec<Log> = buf
+            .lines()
+            .map(|l| parse_log(l.into()).unwrap().1)
+            .collect();
+        assert_eq!(Some(23047), part2(&logs));
+    }
+}
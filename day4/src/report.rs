@@ -0,0 +1,237 @@
+// Everything the puzzle asks about a guard's sleeping habits, worked out
+// once from the logs so each part is just a question over it
+
+use crate::validate::{validate_lenient, Shift};
+use crate::Log;
+use chrono::prelude::*;
+use chrono::Duration;
+use std::collections::BTreeMap;
+
+/// The stretch of each day the histograms cover, one bucket per minute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    pub start: NaiveTime,
+    /// At most a day's worth
+    pub minutes: usize,
+}
+
+impl Window {
+    pub fn new(start: NaiveTime, minutes: usize) -> Window {
+        Window {
+            start,
+            minutes: minutes.min(24 * 60),
+        }
+    }
+
+    /// Which bucket, if any, the minute starting at `ts` falls in
+    pub fn bucket(&self, ts: DateTime<Utc>) -> Option<usize> {
+        let since = ts
+            .time()
+            .signed_duration_since(self.start)
+            .num_minutes()
+            .rem_euclid(24 * 60) as usize;
+        if since < self.minutes {
+            Some(since)
+        } else {
+            None
+        }
+    }
+
+    /// The time of day a bucket starts at
+    pub fn time_of(&self, bucket: usize) -> NaiveTime {
+        self.start + Duration::minutes(bucket as i64)
+    }
+}
+
+/// The midnight hour, which is all the puzzle's guards work
+impl Default for Window {
+    fn default() -> Window {
+        Window::new(NaiveTime::from_hms(0, 0, 0), 60)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuardSleep {
+    /// How many shifts the guard was asleep during each minute of the window
+    pub histogram: Vec<usize>,
+    /// Every minute asleep, in the window or not
+    pub total_minutes: usize,
+    pub shifts: usize,
+}
+
+impl GuardSleep {
+    fn new(window: &Window) -> GuardSleep {
+        GuardSleep {
+            histogram: vec![0; window.minutes],
+            total_minutes: 0,
+            shifts: 0,
+        }
+    }
+
+    /// The bucket the guard is most often asleep in and how many shifts
+    /// they were asleep for it, earliest on a tie. None if they never slept
+    /// during the window.
+    pub fn most_likely_minute(&self) -> Option<(usize, usize)> {
+        let (minute, &freq) = self
+            .histogram
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, &freq)| freq)?;
+        if freq == 0 {
+            None
+        } else {
+            Some((minute, freq))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SleepReport {
+    pub window: Window,
+    pub guards: BTreeMap<String, GuardSleep>,
+}
+
+impl SleepReport {
+    /// Builds the report for the midnight hour from leniently validated logs
+    pub fn new(logs: &[Log]) -> SleepReport {
+        SleepReport::from_shifts(&validate_lenient(logs).shifts, Window::default())
+    }
+
+    pub fn from_shifts(shifts: &[Shift], window: Window) -> SleepReport {
+        let mut guards: BTreeMap<String, GuardSleep> = BTreeMap::new();
+        for shift in shifts {
+            let sleep = guards
+                .entry(shift.guard.clone())
+                .or_insert_with(|| GuardSleep::new(&window));
+            sleep.shifts += 1;
+            for nap in &shift.naps {
+                let mut minute = nap.start;
+                while minute < nap.end {
+                    sleep.total_minutes += 1;
+                    if let Some(bucket) = window.bucket(minute) {
+                        sleep.histogram[bucket] += 1;
+                    }
+                    minute = minute + Duration::minutes(1);
+                }
+            }
+        }
+        SleepReport { window, guards }
+    }
+
+    pub fn guard(&self, id: &str) -> Option<&GuardSleep> {
+        self.guards.get(id)
+    }
+
+    /// The guard with the most minutes asleep overall
+    pub fn sleepiest(&self) -> Option<(&str, &GuardSleep)> {
+        self.guards
+            .iter()
+            .rev()
+            .max_by_key(|(_, sleep)| sleep.total_minutes)
+            .map(|(guard, sleep)| (guard.as_str(), sleep))
+    }
+
+    /// The guard most often asleep in the same bucket, with that bucket and
+    /// how often
+    pub fn most_consistent(&self) -> Option<(&str, usize, usize)> {
+        self.guards
+            .iter()
+            .rev()
+            .filter_map(|(guard, sleep)| {
+                let (minute, freq) = sleep.most_likely_minute()?;
+                Some((guard.as_str(), minute, freq))
+            })
+            .max_by_key(|&(_, _, freq)| freq)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validate::validate_strict;
+
+    const EXAMPLE: &str = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+
+    #[test]
+    fn test_report() {
+        let mut logs = crate::parse_input(EXAMPLE).unwrap();
+        logs.reverse();
+        let report = SleepReport::new(&logs);
+
+        let ten = report.guard("10").unwrap();
+        assert_eq!(50, ten.total_minutes);
+        assert_eq!(2, ten.shifts);
+        assert_eq!(Some((24, 2)), ten.most_likely_minute());
+        assert_eq!(1, ten.histogram[5]);
+        assert_eq!(0, ten.histogram[29]);
+
+        let ninety_nine = report.guard("99").unwrap();
+        assert_eq!(30, ninety_nine.total_minutes);
+        assert_eq!(3, ninety_nine.shifts);
+        assert_eq!(Some((45, 3)), ninety_nine.most_likely_minute());
+
+        assert_eq!(Some("10"), report.sleepiest().map(|(g, _)| g));
+        assert_eq!(Some(("99", 45, 3)), report.most_consistent());
+    }
+
+    #[test]
+    fn test_never_asleep() {
+        let logs = crate::parse_input("[1518-11-01 00:00] Guard #10 begins shift").unwrap();
+        let report = SleepReport::new(&logs);
+        assert_eq!(1, report.guard("10").unwrap().shifts);
+        assert_eq!(None, report.guard("10").unwrap().most_likely_minute());
+        assert_eq!(None, report.most_consistent());
+        assert_eq!(None, SleepReport::new(&[]).sleepiest());
+    }
+
+    #[test]
+    fn test_across_midnight() {
+        let logs = crate::parse_input(
+            "[1518-11-01 23:30] Guard #10 begins shift
+[1518-11-01 23:50] falls asleep
+[1518-11-02 00:10] wakes up
+[1518-11-02 22:00] Guard #10 begins shift
+[1518-11-02 23:55] falls asleep
+[1518-11-03 01:05] wakes up",
+        )
+        .unwrap();
+        let shifts = validate_strict(&logs).unwrap().shifts;
+
+        let report = SleepReport::from_shifts(&shifts, Window::default());
+        let ten = report.guard("10").unwrap();
+        assert_eq!(20 + 70, ten.total_minutes);
+        assert_eq!(2, ten.histogram[9]);
+        assert_eq!(1, ten.histogram[10]);
+        assert_eq!(Some((0, 2)), ten.most_likely_minute());
+
+        let late = Window::new(NaiveTime::from_hms(23, 0, 0), 120);
+        let report = SleepReport::from_shifts(&shifts, late);
+        let ten = report.guard("10").unwrap();
+        assert_eq!(120, ten.histogram.len());
+        assert_eq!(1, ten.histogram[50]);
+        assert_eq!(2, ten.histogram[55]);
+        assert_eq!(1, ten.histogram[70]);
+        assert_eq!(1, ten.histogram[119]);
+        assert_eq!(0, ten.histogram[40]);
+        assert_eq!(Some((55, 2)), ten.most_likely_minute());
+        assert_eq!(NaiveTime::from_hms(23, 55, 0), late.time_of(55));
+        assert_eq!(None, late.bucket(Utc.ymd(1518, 11, 2).and_hms(1, 0, 0)));
+    }
+}
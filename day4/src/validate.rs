@@ -0,0 +1,196 @@
+// Checks that the logs, once sorted, read as a sensible sequence of shifts
+// and splits them up into those shifts
+
+use crate::{GuardState, Log};
+use chrono::prelude::*;
+use chrono::Duration;
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Anomaly {
+    /// Fell asleep or woke up before any guard began a shift
+    NoGuard(DateTime<Utc>),
+    /// Fell asleep while already asleep
+    AlreadyAsleep(DateTime<Utc>),
+    /// Woke up while already awake
+    AlreadyAwake(DateTime<Utc>),
+    /// Fell asleep at this time and the shift ended before they woke
+    EndedAsleep(DateTime<Utc>),
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (ts, what) = match self {
+            Anomaly::NoGuard(ts) => (ts, "entry before any guard begins a shift"),
+            Anomaly::AlreadyAsleep(ts) => (ts, "falls asleep while already asleep"),
+            Anomaly::AlreadyAwake(ts) => (ts, "wakes up while already awake"),
+            Anomaly::EndedAsleep(ts) => (ts, "falls asleep and never wakes up"),
+        };
+        write!(f, "[{}] {}", ts.format("%Y-%m-%d %H:%M"), what)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shift {
+    pub guard: String,
+    pub begins: DateTime<Utc>,
+    /// From falling asleep up to waking
+    pub naps: Vec<Range<DateTime<Utc>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checked {
+    /// Every shift, in time order
+    pub shifts: Vec<Shift>,
+    /// Anything that was worked around, in time order. Always empty from
+    /// `validate_strict`.
+    pub anomalies: Vec<Anomaly>,
+}
+
+/// Sorts the logs and splits them into shifts, failing with every anomaly
+/// found if there are any
+pub fn validate_strict(logs: &[Log]) -> Result<Checked, Vec<Anomaly>> {
+    let checked = validate_lenient(logs);
+    if checked.anomalies.is_empty() {
+        Ok(checked)
+    } else {
+        Err(checked.anomalies)
+    }
+}
+
+/// Sorts the logs and splits them into shifts, noting anomalies and working
+/// around them: stray entries are dropped and a guard still asleep when the
+/// shift ends wakes at the next 01:00
+pub fn validate_lenient(logs: &[Log]) -> Checked {
+    let mut logs = logs.to_vec();
+    logs.sort_by_key(|a| a.ts);
+
+    let mut shifts: Vec<Shift> = Vec::new();
+    let mut anomalies = Vec::new();
+    // when the current guard fell asleep
+    let mut asleep: Option<DateTime<Utc>> = None;
+
+    for log in logs {
+        if let GuardState::BeginShift(guard) = log.state {
+            end_shift(shifts.last_mut(), &mut asleep, &mut anomalies);
+            shifts.push(Shift {
+                guard,
+                begins: log.ts,
+                naps: Vec::new(),
+            });
+            continue;
+        }
+
+        let shift = match shifts.last_mut() {
+            Some(shift) => shift,
+            None => {
+                anomalies.push(Anomaly::NoGuard(log.ts));
+                continue;
+            }
+        };
+        match (log.state, asleep) {
+            (GuardState::Asleep, None) => asleep = Some(log.ts),
+            (GuardState::Asleep, Some(_)) => anomalies.push(Anomaly::AlreadyAsleep(log.ts)),
+            (GuardState::Wake, Some(from)) => {
+                shift.naps.push(from..log.ts);
+                asleep = None;
+            }
+            (GuardState::Wake, None) => anomalies.push(Anomaly::AlreadyAwake(log.ts)),
+            (GuardState::BeginShift(_), _) => unreachable!(),
+        }
+    }
+    end_shift(shifts.last_mut(), &mut asleep, &mut anomalies);
+
+    Checked { shifts, anomalies }
+}
+
+// Wakes the guard at the next 01:00 if the shift is ending with them still
+// asleep
+fn end_shift(
+    shift: Option<&mut Shift>,
+    asleep: &mut Option<DateTime<Utc>>,
+    anomalies: &mut Vec<Anomaly>,
+) {
+    if let (Some(shift), Some(from)) = (shift, asleep.take()) {
+        let mut wake = from.date().and_hms(1, 0, 0);
+        if wake <= from {
+            wake = wake + Duration::days(1);
+        }
+        shift.naps.push(from..wake);
+        anomalies.push(Anomaly::EndedAsleep(from));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_input;
+
+    fn at(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+        Utc.ymd(1518, 11, d).and_hms(h, m, 0)
+    }
+
+    fn naps(shift: &Shift) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        shift.naps.iter().map(|n| (n.start, n.end)).collect()
+    }
+
+    #[test]
+    fn test_clean() {
+        let logs = parse_input(
+            "[1518-11-02 00:40] falls asleep
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:50] wakes up",
+        )
+        .unwrap();
+        let checked = validate_strict(&logs).unwrap();
+        assert_eq!(1, checked.shifts.len());
+        assert_eq!("99", checked.shifts[0].guard);
+        assert_eq!(at(1, 23, 58), checked.shifts[0].begins);
+        assert_eq!(vec![(at(2, 0, 40), at(2, 0, 50))], naps(&checked.shifts[0]));
+        assert!(checked.anomalies.is_empty());
+    }
+
+    #[test]
+    fn test_anomalies() {
+        let logs = parse_input(
+            "[1518-11-01 00:05] falls asleep
+[1518-11-01 00:06] Guard #10 begins shift
+[1518-11-01 00:10] falls asleep
+[1518-11-01 00:12] falls asleep
+[1518-11-01 00:20] wakes up
+[1518-11-01 00:21] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-02 00:00] Guard #99 begins shift
+[1518-11-02 00:45] falls asleep",
+        )
+        .unwrap();
+        let expected = vec![
+            Anomaly::NoGuard(at(1, 0, 5)),
+            Anomaly::AlreadyAsleep(at(1, 0, 12)),
+            Anomaly::AlreadyAwake(at(1, 0, 21)),
+            Anomaly::EndedAsleep(at(1, 0, 30)),
+            Anomaly::EndedAsleep(at(2, 0, 45)),
+        ];
+        assert_eq!(Err(expected.clone()), validate_strict(&logs));
+
+        let checked = validate_lenient(&logs);
+        assert_eq!(expected, checked.anomalies);
+        assert_eq!(
+            vec![(at(1, 0, 10), at(1, 0, 20)), (at(1, 0, 30), at(1, 1, 0))],
+            naps(&checked.shifts[0])
+        );
+        assert_eq!(vec![(at(2, 0, 45), at(2, 1, 0))], naps(&checked.shifts[1]));
+
+        assert_eq!(
+            "[1518-11-01 00:12] falls asleep while already asleep",
+            Anomaly::AlreadyAsleep(at(1, 0, 12)).to_string()
+        );
+    }
+
+    #[test]
+    fn test_real() {
+        let logs = parse_input(include_str!("../../input-day4")).unwrap();
+        assert!(validate_strict(&logs).is_ok());
+    }
+}
@@ -0,0 +1,170 @@
+#![allow(clippy::needless_borrow)]
+
+pub mod rules;
+
+use crate::rules::{AsciiCase, ReactionRule};
+use aoc_core::{Answer, ParseError, Solution};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Vec<u8>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// Units are ASCII letters, whitespace anywhere is skipped
+pub fn parse_input(buf: &str) -> Result<Vec<u8>, ParseError> {
+    let mut polymer = Vec::with_capacity(buf.len());
+    for (i, &unit) in buf.as_bytes().iter().enumerate() {
+        if unit.is_ascii_alphabetic() {
+            polymer.push(unit);
+        } else if !unit.is_ascii_whitespace() {
+            return Err(ParseError::locate(buf, 1, i, "unexpected character"));
+        }
+    }
+    Ok(polymer)
+}
+
+/// Reacts the polymer all the way down under the puzzle's rules: two units
+/// react when they're the same ASCII letter in opposite cases, any other
+/// byte never reacts. ASCII whitespace isn't a unit at all and is dropped.
+pub fn react(polymer: &[u8]) -> Vec<u8> {
+    react_with(polymer, &AsciiCase)
+}
+
+/// Reacts the polymer all the way down in a single pass, keeping the
+/// surviving units on a stack
+pub fn react_with<T: Copy, R: ReactionRule<T>>(polymer: &[T], rule: &R) -> Vec<T> {
+    let mut stack: Vec<T> = Vec::with_capacity(polymer.len());
+    for &unit in polymer {
+        if rule.ignores(unit) {
+            continue;
+        }
+        match stack.last() {
+            Some(&top) if rule.reacts(top, unit) => {
+                stack.pop();
+            }
+            _ => stack.push(unit),
+        }
+    }
+    stack
+}
+
+pub fn part1(buf: &[u8]) -> usize {
+    react(buf).len()
+}
+
+pub fn part2(buf: &[u8]) -> usize {
+    removal_lengths(buf).values().cloned().min().unwrap_or(0)
+}
+
+/// For every unit type (lowercased letter) in the polymer, the length of
+/// the fully reacted polymer once all units of that type are taken out.
+///
+/// The same polymer comes out whatever order pairs react in, so taking a
+/// type out of the reacted polymer and reacting again gives the same result
+/// as taking it out of the original. Each removal starts from the reacted
+/// polymer, and the removals run in parallel.
+pub fn removal_lengths(polymer: &[u8]) -> BTreeMap<u8, usize> {
+    let types: BTreeSet<u8> = polymer
+        .iter()
+        .filter(|u| u.is_ascii_alphabetic())
+        .map(u8::to_ascii_lowercase)
+        .collect();
+    let reacted = react(polymer);
+
+    types
+        .into_par_iter()
+        .map(|t| {
+            let removed: Vec<u8> = reacted
+                .iter()
+                .filter(|u| !u.eq_ignore_ascii_case(&t))
+                .cloned()
+                .collect();
+            (t, react(&removed).len())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_react() {
+        assert_eq!(b"dabCBAcaDA".to_vec(), react(b"dabAcCaCBAcCcaDA"));
+        assert_eq!(b"".to_vec(), react(b"aA\n"));
+        assert_eq!(b"abAB".to_vec(), react(b"abAB"));
+        assert_eq!(b"aabAAB".to_vec(), react(b"aabAAB"));
+        // nothing but letters ever reacts, bytes below 32 included
+        assert_eq!(
+            vec![0, 1, 33, 1, 0x80, 0xa0],
+            react(&[0, 1, 33, 1, 0x80, 0xa0])
+        );
+        assert_eq!(b"ab".to_vec(), react(b" a\tb\r\n"));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(Ok(b"dabAc".to_vec()), parse_input("dabAc\n"));
+        assert_eq!(
+            Err(ParseError::new(1, 4, "dab1Ac", "unexpected character")),
+            parse_input("dab1Ac")
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let buf = "dabAcCaCBAcCcaDA".as_bytes();
+        assert_eq!(10, part1(&buf));
+    }
+
+    #[test]
+    fn test_part2() {
+        let buf = "dabAcCaCBAcCcaDA".as_bytes();
+        assert_eq!(4, part2(&buf));
+    }
+
+    #[test]
+    fn test_removal_lengths() {
+        let lengths = removal_lengths(b"dabAcCaCBAcCcaDA");
+        let expected: BTreeMap<u8, usize> = vec![(b'a', 6), (b'b', 8), (b'c', 4), (b'd', 6)]
+            .into_iter()
+            .collect();
+        assert_eq!(expected, lengths);
+
+        // 'x' reacts away completely but is still a unit type
+        assert_eq!(Some(&2), removal_lengths(b"xXab").get(&b'x'));
+        assert_eq!(0, removal_lengths(b"").len());
+    }
+
+    #[test]
+    fn test_part1_real() {
+        let buf = include_bytes!("../../input-day5");
+        assert_eq!(11590, part1(buf));
+        let mut buf = buf.to_vec();
+        buf.push(b'\n');
+        assert_eq!(11590, part1(&buf));
+    }
+
+    #[test]
+    fn test_part2_real() {
+        let buf = include_bytes!("../../input-day5");
+        assert_eq!(4504, part2(buf));
+        assert_eq!(26, removal_lengths(buf).len());
+    }
+}
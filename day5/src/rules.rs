@@ -0,0 +1,121 @@
+// Which units annihilate when they end up next to each other. The puzzle's
+// rule is `AsciiCase`, the others let the same reducer cancel out anything
+// else that comes in pairs.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+pub trait ReactionRule<T> {
+    /// Whether `left` immediately followed by `right` react
+    fn reacts(&self, left: T, right: T) -> bool;
+
+    /// Whether `unit` is filler rather than part of the polymer, so it's
+    /// dropped and never gets between two units that would react
+    fn ignores(&self, _unit: T) -> bool {
+        false
+    }
+}
+
+/// Any closure comparing two units is a rule that ignores nothing
+impl<T, F: Fn(T, T) -> bool> ReactionRule<T> for F {
+    fn reacts(&self, left: T, right: T) -> bool {
+        self(left, right)
+    }
+}
+
+/// The same ASCII letter in opposite cases, over bytes. Any other byte never
+/// reacts, and ASCII whitespace is ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AsciiCase;
+
+impl ReactionRule<u8> for AsciiCase {
+    fn reacts(&self, left: u8, right: u8) -> bool {
+        left != right && left.eq_ignore_ascii_case(&right)
+    }
+
+    fn ignores(&self, unit: u8) -> bool {
+        unit.is_ascii_whitespace()
+    }
+}
+
+/// Like `AsciiCase` for any letter Unicode has cases for: one lowercase and
+/// one uppercase unit that lowercase to the same thing. Whitespace is
+/// ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnicodeCase;
+
+impl ReactionRule<char> for UnicodeCase {
+    fn reacts(&self, left: char, right: char) -> bool {
+        let opposite = (left.is_lowercase() && right.is_uppercase())
+            || (left.is_uppercase() && right.is_lowercase());
+        opposite && left.to_lowercase().eq(right.to_lowercase())
+    }
+
+    fn ignores(&self, unit: char) -> bool {
+        unit.is_whitespace()
+    }
+}
+
+/// Exactly the listed (left, right) pairs react, in that order
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairTable<T: Eq + Hash> {
+    pairs: HashSet<(T, T)>,
+}
+
+impl<T: Copy + Eq + Hash> PairTable<T> {
+    pub fn new<I: IntoIterator<Item = (T, T)>>(pairs: I) -> PairTable<T> {
+        PairTable {
+            pairs: pairs.into_iter().collect(),
+        }
+    }
+
+    /// Each pair reacts whichever way round it comes
+    pub fn symmetric<I: IntoIterator<Item = (T, T)>>(pairs: I) -> PairTable<T> {
+        PairTable::new(pairs.into_iter().flat_map(|(a, b)| vec![(a, b), (b, a)]))
+    }
+}
+
+impl<T: Copy + Eq + Hash> ReactionRule<T> for PairTable<T> {
+    fn reacts(&self, left: T, right: T) -> bool {
+        self.pairs.contains(&(left, right))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::react_with;
+
+    fn react_str<R: ReactionRule<char>>(polymer: &str, rule: &R) -> String {
+        let units: Vec<char> = polymer.chars().collect();
+        react_with(&units, rule).into_iter().collect()
+    }
+
+    #[test]
+    fn test_unicode_case() {
+        assert_eq!("dabCBAcaDA", react_str("dabAcCaCBAcCcaDA", &UnicodeCase));
+        assert_eq!("", react_str("ÉéσΣ ωΩ", &UnicodeCase));
+        // the Kelvin sign lowercases to k but is no more lowercase than K
+        assert_eq!("\u{212a}K", react_str("\u{212a}K", &UnicodeCase));
+        assert_eq!("", react_str("\u{212a}k", &UnicodeCase));
+        assert_eq!("aa12", react_str("aa12", &UnicodeCase));
+    }
+
+    #[test]
+    fn test_pair_table() {
+        let brackets = PairTable::new(vec![('(', ')'), ('[', ']'), ('{', '}')]);
+        assert_eq!("", react_str("{[()()]}", &brackets));
+        assert_eq!(")(", react_str(")(", &brackets));
+        assert_eq!("[(])", react_str("[(])", &brackets));
+
+        let directions = PairTable::symmetric(vec![("N", "S"), ("E", "W")]);
+        let path = ["N", "E", "W", "S", "W", "N", "N", "S", "E"];
+        assert_eq!(vec!["W", "N", "E"], react_with(&path, &directions));
+    }
+
+    #[test]
+    fn test_closure() {
+        let opposite = |a: i32, b: i32| a == -b;
+        assert_eq!(vec![4], react_with(&[1, 2, -2, -1, 4], &opposite));
+    }
+}
@@ -1,15 +1,26 @@
-use std::io::prelude::*;
+// Renders which day6 point is closest to each cell to day6-viz.png
+//
+//   day6-viz [<input path> | -]
 
-fn main() -> Result<(), std::io::Error> {
+use day6::manhattan_distance;
+use std::process;
+
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "input-day6".into());
+    if let Err(e) = render(&path) {
+        eprintln!("error: {}: {}", path, e);
+        process::exit(1);
+    }
+}
+
+fn render(path: &str) -> Result<(), Box<dyn std::error::Error>> {
     use image::{ImageBuffer, Rgb};
     use palette::{Gradient, LinSrgb};
-    use std::fs::File;
 
-    let mut file = File::open("input-day6")?;
-    let mut buf = String::new();
-    file.read_to_string(&mut buf)?;
-
-    let points: Vec<Coord> = buf.lines().map(parse_line).collect();
+    let buf = aoc_core::read_input(path)?;
+    let points = day6::parse_input(&buf)?;
 
     let gradient = Gradient::new(vec![
         LinSrgb::from(to_floats((0x36, 0x37, 0x55))),
@@ -37,7 +48,7 @@ fn main() -> Result<(), std::io::Error> {
     });
 
     println!("saving day6-viz.png");
-    img.save("day6-viz.png").unwrap();
+    img.save("day6-viz.png")?;
 
     Ok(())
 }
@@ -57,23 +68,3 @@ fn to_rgbu8(color: (f64, f64, f64)) -> (u8, u8, u8) {
     let b = (b * 255.0) as u8;
     (r, g, b)
 }
-
-type Coord = (isize, isize);
-
-#[inline]
-fn manhattan_distance(lhs: &Coord, rhs: &Coord) -> usize {
-    use std::isize;
-    let (l_x, l_y) = lhs;
-    let (r_x, r_y) = rhs;
-
-    let x = (*l_x as isize - *r_x as isize).abs();
-    let y = (*l_y as isize - *r_y as isize).abs();
-    (x + y) as usize
-}
-
-fn parse_line(ln: &str) -> Coord {
-    let mut sp = ln.split(", ").take(2).map(|x| x.parse::<isize>().unwrap());
-    let a = sp.next().unwrap();
-    let b = sp.next().unwrap();
-    (a, b)
-}
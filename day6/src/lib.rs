@@ -0,0 +1,288 @@
+#![allow(clippy::legacy_numeric_constants, clippy::unnecessary_cast)]
+
+use aoc_core::{Answer, ParseError, Solution};
+use rayon::prelude::*;
+
+pub type Coord = (isize, isize);
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Vec<Coord>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input, 10000).into()
+    }
+}
+
+pub fn parse_input(buf: &str) -> Result<Vec<Coord>, ParseError> {
+    buf.lines()
+        .enumerate()
+        .map(|(i, ln)| parse_line(i + 1, ln))
+        .collect()
+}
+
+/// Size of the largest finite area, if there is one.
+///
+/// Only the bounding box of the points needs checking. Past its right edge,
+/// say, every point is to the left, so each step right adds one to every
+/// distance and whichever point is closest stays closest. Any cell outside
+/// the box therefore belongs to the same point as the nearest cell on the
+/// box's edge, and an area is infinite exactly when it reaches the edge.
+///
+/// Rows are swept in parallel, each rayon job tallying into its own
+/// per-point counts, and the counts are summed at the end.
+pub fn part1(points: &[Coord]) -> Option<usize> {
+    let bounds = bounding_box(points)?;
+    let ((min_x, min_y), (max_x, max_y)) = bounds;
+    let empty = || (vec![0; points.len()], vec![false; points.len()]);
+
+    let (areas, infinite) = (min_y..=max_y)
+        .into_par_iter()
+        .fold(empty, |(mut areas, mut infinite), y| {
+            for x in min_x..=max_x {
+                if let Some(i) = closest(points, (x, y)) {
+                    areas[i] += 1;
+                    infinite[i] |= on_edge(&(x, y), bounds);
+                }
+            }
+            (areas, infinite)
+        })
+        .reduce(empty, |(mut areas, mut infinite), (more, more_infinite)| {
+            for i in 0..areas.len() {
+                areas[i] += more[i];
+                infinite[i] |= more_infinite[i];
+            }
+            (areas, infinite)
+        });
+
+    areas
+        .into_iter()
+        .zip(infinite)
+        .filter(|(_, infinite)| !infinite)
+        .map(|(area, _)| area)
+        .max()
+}
+
+/// How many cells have a total distance to every point under `threshold`.
+///
+/// The total splits into a sum over x and a sum over y, so those are
+/// worked out once per column and once per row, and the cells are counted
+/// by walking the sorted column sums against the sorted row sums.
+pub fn part2(pts: &[Coord], threshold: usize) -> usize {
+    let ((min_x, min_y), (max_x, max_y)) = match bounding_box(pts) {
+        Some(bounds) => bounds,
+        None => return 0,
+    };
+    // past this far out of the box the sum over that axis alone is too big
+    let margin = (threshold / pts.len() + 1) as isize;
+
+    let xs: Vec<isize> = pts.iter().map(|&(x, _)| x).collect();
+    let ys: Vec<isize> = pts.iter().map(|&(_, y)| y).collect();
+    let mut x_sums = distance_sums(xs, min_x - margin, max_x + margin);
+    let mut y_sums = distance_sums(ys, min_y - margin, max_y + margin);
+    x_sums.sort_unstable();
+    y_sums.sort_unstable();
+
+    // y_sums[..fits] are the rows that still fit with the current column
+    let mut fits = y_sums.len();
+    let mut count = 0;
+    for x_sum in x_sums {
+        while fits > 0 && x_sum + y_sums[fits - 1] >= threshold {
+            fits -= 1;
+        }
+        count += fits;
+    }
+    count
+}
+
+// Total distance from each position in `from..=to` to all of `values`
+fn distance_sums(mut values: Vec<isize>, from: isize, to: isize) -> Vec<usize> {
+    values.sort_unstable();
+    let mut sum: isize = values.iter().map(|v| (v - from).abs()).sum();
+    // how many values are at or before the current position
+    let mut before = 0;
+    let mut sums = Vec::with_capacity((to - from + 1).max(0) as usize);
+    for pos in from..=to {
+        sums.push(sum as usize);
+        while before < values.len() && values[before] <= pos {
+            before += 1;
+        }
+        // a step on moves away from those and towards the rest
+        sum += before as isize - (values.len() - before) as isize;
+    }
+    sums
+}
+
+// Index of the one point closest to `cell`, None on a tie
+fn closest(points: &[Coord], cell: Coord) -> Option<usize> {
+    let mut best = None;
+    let mut best_dist = usize::MAX;
+    for (i, pt) in points.iter().enumerate() {
+        let dist = manhattan_distance(pt, &cell);
+        if dist < best_dist {
+            best = Some(i);
+            best_dist = dist;
+        } else if dist == best_dist {
+            best = None;
+        }
+    }
+    best
+}
+
+/// The smallest box holding every point, as its (min, max) corners
+pub fn bounding_box(points: &[Coord]) -> Option<(Coord, Coord)> {
+    let (&first, rest) = points.split_first()?;
+    Some(rest.iter().fold(
+        (first, first),
+        |((min_x, min_y), (max_x, max_y)), &(x, y)| {
+            ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+        },
+    ))
+}
+
+fn on_edge(&(x, y): &Coord, ((min_x, min_y), (max_x, max_y)): (Coord, Coord)) -> bool {
+    x == min_x || x == max_x || y == min_y || y == max_y
+}
+
+#[inline]
+pub fn manhattan_distance(lhs: &Coord, rhs: &Coord) -> usize {
+    use std::isize;
+    let (l_x, l_y) = lhs;
+    let (r_x, r_y) = rhs;
+
+    let x = (*l_x as isize - *r_x as isize).abs();
+    let y = (*l_y as isize - *r_y as isize).abs();
+    (x + y) as usize
+}
+
+fn parse_line(line: usize, ln: &str) -> Result<Coord, ParseError> {
+    let sep = ln.find(", ").ok_or_else(|| {
+        ParseError::new(line, ln.chars().count() + 1, ln, "expected \", \"")
+    })?;
+    let a = ln[..sep]
+        .parse()
+        .map_err(|_| ParseError::new(line, 1, ln, "expected a number"))?;
+    let b = ln[sep + 2..]
+        .parse()
+        .map_err(|_| ParseError::new(line, ln[..sep].chars().count() + 3, ln, "expected a number"))?;
+    Ok((a, b))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let buf = "1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9";
+        let coords = parse_input(buf).unwrap();
+        assert_eq!(Some(17), part1(&coords));
+    }
+
+    #[test]
+    fn test_part2() {
+        let buf = "1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9";
+        let coords = parse_input(buf).unwrap();
+        assert_eq!(16, part2(&coords, 32));
+    }
+
+    #[test]
+    fn test_part2_brute_force() {
+        let pts = [(3, -2), (0, 0), (7, 4), (3, 3), (-1, 5), (3, -2)];
+        for &threshold in &[0, 1, 6, 25, 40, 41, 100] {
+            let mut expected = 0;
+            for x in -30..40 {
+                for y in -30..40 {
+                    let total: usize = pts.iter().map(|p| manhattan_distance(p, &(x, y))).sum();
+                    if total < threshold {
+                        expected += 1;
+                    }
+                }
+            }
+            assert_eq!(expected, part2(&pts, threshold), "{}", threshold);
+        }
+        assert_eq!(0, part2(&[], 10));
+    }
+
+    #[test]
+    fn test_closest() {
+        let pts = [(0, 0), (4, 0), (0, 0)];
+        assert_eq!(None, closest(&pts, (1, 0)));
+        assert_eq!(Some(1), closest(&pts[..2], (3, 0)));
+        assert_eq!(None, closest(&pts[..2], (2, 0)));
+        assert_eq!(Some(0), closest(&pts[..2], (1, 0)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(ParseError::new(2, 4, "1, y", "expected a number")),
+            parse_input("1, 1\n1, y")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 4, "1 1", "expected \", \"")),
+            parse_input("1 1")
+        );
+    }
+
+    #[test]
+    fn test_infinite() {
+        let bounds = ((-20, -5), (20, 5));
+        assert!(on_edge(&(-20, 0), bounds));
+        assert!(on_edge(&(0, -5), bounds));
+        assert!(on_edge(&(20, 0), bounds));
+        assert!(on_edge(&(0, 5), bounds));
+        assert!(!on_edge(&(0, 0), bounds));
+
+        assert_eq!(
+            Some(((-20, -5), (20, 5))),
+            bounding_box(&[(0, 5), (-20, 0), (20, -5)])
+        );
+        assert_eq!(None, bounding_box(&[]));
+
+        // every area reaches out of the box, or there's no box at all
+        assert_eq!(None, part1(&[(0, 0), (5, 5)]));
+        assert_eq!(None, part1(&[]));
+        // ties all round leave the middle point only its own square
+        assert_eq!(Some(1), part1(&[(0, 0), (0, 2), (2, 0), (0, -2), (-2, 0)]));
+    }
+
+    #[inline]
+    fn real_input() -> Vec<Coord> {
+        let buf = include_str!("../../input-day6");
+        parse_input(buf).unwrap()
+    }
+
+    #[test]
+    fn test_part1_real() {
+        let coords = real_input();
+        assert_eq!(Some(4016), part1(&coords));
+        assert_eq!(46306, part2(&coords, 10000));
+    }
+
+    #[test]
+    fn test_part2_real() {
+        let coords = real_input();
+        assert_eq!(46306, part2(&coords, 10000));
+    }
+}
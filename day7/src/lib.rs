@@ -0,0 +1,250 @@
+#![allow(clippy::len_zero, clippy::needless_return, clippy::unnecessary_unwrap, clippy::useless_conversion)]
+
+#[macro_use]
+extern crate nom;
+extern crate chrono;
+extern crate itertools;
+extern crate regex;
+extern crate tap;
+extern crate topological_sort;
+
+use aoc_core::{finish, Answer, ParseError, Solution};
+use std::collections::HashSet;
+use tap::TapOps;
+use topological_sort::TopologicalSort;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<(u8, u8)>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input, 5, 60).into()
+    }
+}
+
+pub fn parse_input(buf: &str) -> Result<Vec<(u8, u8)>, ParseError> {
+    buf.lines()
+        .enumerate()
+        .map(|(i, ln)| finish(ln, i + 1, parse_step(ln.as_bytes())))
+        .collect()
+}
+
+pub fn part1(input: &[(u8, u8)]) -> String {
+    use std::collections::HashSet;
+
+    let mut popped_prec: HashSet<&u8> = HashSet::new();
+    let mut order: Vec<&u8> = Vec::new();
+    loop {
+        let mut topology: TopologicalSort<&u8> = TopologicalSort::new();
+        for (prec, succ) in input.iter().filter(|(p, _)| !popped_prec.contains(p)) {
+            topology.add_dependency(prec, succ);
+        }
+
+        let mut to_order = topology.pop_all();
+        if to_order.len() == 0 {
+            break;
+        }
+
+        to_order.sort();
+        let prec = to_order[0];
+        order.push(prec);
+        popped_prec.insert(prec);
+    }
+
+    // add everything that no longer has a predecessor
+    let all_the_things: HashSet<&u8> = input.iter().fold(HashSet::new(), |acc, (a, b)| {
+        acc.tap(|accum| {
+            accum.insert(a);
+            accum.insert(b);
+        })
+    });
+    for succ in all_the_things.difference(&popped_prec) {
+        order.push(succ);
+    }
+
+    let mut outstring = String::new();
+    for x in order {
+        outstring.push((*x).into());
+    }
+    outstring
+}
+
+#[inline]
+fn letter_to_time(letter: &Option<u8>, time_per_step: usize) -> usize {
+    // A == 1 seconds
+    match letter {
+        Some(l) => *l as usize - 64 + time_per_step,
+        None => 0,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Worker {
+    job: Option<u8>,
+    done_t: usize,
+}
+
+pub fn part2(input: &[(u8, u8)], num_workers: usize, time_per_step: usize) -> usize {
+    let mut popped_prec = HashSet::new();
+    let mut working = HashSet::new();
+    let mut workers = vec![
+        Worker {
+            job: None,
+            done_t: 0
+        };
+        num_workers
+    ];
+    let mut output = String::new();
+
+    let mut t = 0_usize;
+    loop {
+        // finish jobs
+        for w in workers.iter().filter(|w| w.done_t <= t && w.job.is_some()) {
+            let j = w.job.unwrap();
+            output.push(j.into());
+            popped_prec.insert(j);
+        }
+
+        // get available jobs
+        let mut jobs: Vec<u8> = next_jobs(input, &popped_prec)
+            .iter()
+            .cloned()
+            .filter(|x| !working.contains(x)) // not the ones that are/have been worked
+            .collect();
+
+        // assign new jobs
+        for w in workers.iter_mut().filter(|w| w.done_t <= t) {
+            let job = jobs.pop();
+            if job.is_some() {
+                working.insert(job.unwrap());
+            }
+            w.job = job;
+            w.done_t = t + letter_to_time(&w.job, time_per_step);
+        }
+
+        // are we done?
+        let all_done = workers.iter().all(|w| w.job.is_none());
+        if all_done {
+            break;
+        }
+
+        // tick
+        t += 1
+    }
+    t
+}
+
+fn next_jobs(input: &[(u8, u8)], popped: &HashSet<u8>) -> Vec<u8> {
+    let mut topology: TopologicalSort<&u8> = TopologicalSort::new();
+    for (prec, succ) in input.iter().filter(|(p, _)| !popped.contains(p)) {
+        topology.add_dependency(prec, succ);
+    }
+
+    let mut to_order = topology.pop_all();
+
+    // whatever's left doesn't have a predecessor, so figure out what
+    // we haven't processed and return that
+    if to_order.len() == 0 {
+        let all_the_things: HashSet<u8> =
+            input.iter().cloned().fold(HashSet::new(), |acc, (a, b)| {
+                acc.tap(|accum| {
+                    accum.insert(a);
+                    accum.insert(b);
+                })
+            });
+        let mut succ: Vec<u8> = all_the_things
+            .difference(popped)
+            .into_iter()
+            .cloned()
+            .collect();
+        succ.sort();
+        return succ;
+    }
+
+    to_order.sort();
+    return to_order.iter().map(|x| **x).collect();
+}
+
+const STEPS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+named!(parse_step<&[u8], (u8, u8)>,
+       do_parse!(
+           tag!("Step ") >>
+               prec: one_of!(STEPS) >>
+               tag!(" must be finished before step ") >>
+               succ: one_of!(STEPS) >>
+               tag!(" can begin.") >>
+               ((prec as u8, succ as u8))));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let buf = "Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.";
+        let input = parse_input(buf).unwrap();
+        assert_eq!("CABDFE", part1(&input));
+    }
+
+    #[test]
+    fn test_part2() {
+        let buf = "Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.";
+        let input = parse_input(buf).unwrap();
+        assert_eq!(15, part2(&input, 2, 0));
+    }
+
+    fn real_input() -> Vec<(u8, u8)> {
+        let buf = include_str!("../../input-day7");
+        parse_input(buf).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let buf = "Step C must be finished before step A can begin.
+Step C must be finished before step f can begin.";
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                37,
+                "Step C must be finished before step f can begin.",
+                "unexpected character"
+            )),
+            parse_input(buf)
+        );
+    }
+
+    #[test]
+    fn test_part1_real() {
+        let input = real_input();
+        assert_eq!("EUGJKYFQSCLTWXNIZMAPVORDBH", part1(&input));
+    }
+
+    #[test]
+    fn test_part2_real() {
+        let input = real_input();
+        assert_eq!(1014, part2(&input, 5, 60));
+    }
+}
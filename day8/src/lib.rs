@@ -0,0 +1,147 @@
+#![allow(clippy::needless_borrow)]
+
+use aoc_core::{Answer, ParseError, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Vec<u8>;
+
+    fn parse(buf: &str) -> Result<Self::Input, ParseError> {
+        parse_input(buf)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let (_, metadata) = metadater(input);
+    metadata
+}
+
+// offset, metadata_sum
+fn metadater(input: &[u8]) -> (usize, usize) {
+    let children_n = input[0];
+    let metadata_n = input[1] as usize;
+    let mut offset = 2;
+    let mut metadata = 0;
+    for _n in 0..children_n {
+        let (child_off, child_meta) = metadater(&input[offset..]);
+        metadata += child_meta;
+        offset += child_off;
+    }
+    let my_metadata: usize = input[offset..]
+        .iter()
+        .cloned()
+        .take(metadata_n)
+        .map(|x| x as usize)
+        .sum();
+    metadata += my_metadata;
+    offset += metadata_n;
+    (offset, metadata)
+}
+
+pub fn parse_input(buf: &str) -> Result<Vec<u8>, ParseError> {
+    let mut offset = 0;
+    buf.trim_end()
+        .split(' ')
+        .map(|s| {
+            let start = offset;
+            offset += s.len() + 1;
+            s.parse()
+                .map_err(|_| ParseError::locate(buf, 1, start, "expected a number"))
+        })
+        .collect()
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    let (_, metadata) = metachild(input);
+    metadata
+}
+
+// offset, metadata_sum/val
+fn metachild(input: &[u8]) -> (usize, usize) {
+    let children_n = input[0];
+    let metadata_n = input[1] as usize;
+    let mut offset = 2;
+    let mut child_metadata: Vec<usize> = Vec::new();
+    for _n in 0..children_n {
+        let (child_off, child_meta) = metachild(&input[offset..]);
+        child_metadata.push(child_meta);
+        offset += child_off;
+    }
+    let my_metadata: usize = match children_n {
+        0 => input[offset..]
+            .iter()
+            .cloned()
+            .take(metadata_n)
+            .map(|x| x as usize)
+            .sum(),
+        _ => input[offset..]
+            .iter()
+            .cloned()
+            .take(metadata_n)
+            .map(|i| {
+                child_metadata
+                    .get(i as usize - 1)
+                    .cloned()
+                    .unwrap_or(0usize)
+            })
+            .sum(),
+    };
+    offset += metadata_n;
+    (offset, my_metadata)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let buf = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+        let input = parse_input(&buf).unwrap();
+
+        assert_eq!(138, part1(&input));
+    }
+
+    #[test]
+    fn test_part2() {
+        let buf = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+        let input = parse_input(&buf).unwrap();
+
+        assert_eq!(66, part2(&input));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Err(ParseError::new(1, 7, "2 3 0 300 10", "expected a number")),
+            parse_input("2 3 0 300 10")
+        );
+    }
+
+    fn real_input() -> Vec<u8> {
+        let buf = include_str!("../../input-day8");
+        parse_input(&buf).unwrap()
+    }
+
+    #[test]
+    fn test_part1_real() {
+        let input = real_input();
+        assert_eq!(40977, part1(&input));
+    }
+
+    #[test]
+    fn test_part2_real() {
+        let input = real_input();
+        assert_eq!(27490, part2(&input));
+    }
+}